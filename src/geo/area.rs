@@ -1,7 +1,15 @@
-use num::{CheckedAdd, CheckedSub, Num};
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use num::{CheckedAdd, CheckedSub, Num, NumCast};
+use num::traits::Euclid;
+#[cfg(feature = "rand")]
+use rand::distributions::uniform::SampleUniform;
+#[cfg(feature = "rand")]
+use rand::{Rng, RngCore};
 use crate::geo::area_iter::AreaIterator;
 use crate::geo::direction::Direction;
 use crate::geo::pos::Pos;
+use crate::interval::Interval;
 
 /// An error returned when [`Area<T>`]'s dimension is invalid.
 #[derive(Clone, Debug, Eq, PartialEq)]
@@ -26,6 +34,16 @@ impl<T: Copy + Num + PartialOrd> IntoIterator for Area<T> {
     }
 }
 
+impl<T: Copy + Num + PartialOrd> IntoIterator for &Area<T> {
+    type Item = Pos<T>;
+    type IntoIter = AreaIterator<T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        (*self).into_iter()
+    }
+}
+
 impl<T: PartialOrd> Area<T> {
     /// Returns a new [`Area<T>`].
     pub fn new(max_x: T, max_y: T, min_x: T, min_y: T) -> Result<Self, AreaBoundaryError> {
@@ -41,6 +59,12 @@ impl<T: PartialOrd> Area<T> {
         pos.x >= self.min_x && pos.x <= self.max_x && pos.y >= self.min_y && pos.y <= self.max_y
     }
 
+    /// Checks whether another [`Area<T>`] is fully inside this [`Area<T>`].
+    #[inline]
+    pub fn contains_area(&self, other: &Self) -> bool {
+        other.min_x >= self.min_x && other.max_x <= self.max_x && other.min_y >= self.min_y && other.max_y <= self.max_y
+    }
+
     /// Filters a list of [`Pos<T>`] to the ones inside this [`Area<T>`].
     #[inline]
     pub fn filter_pos<'a>(&'a self, pos: impl IntoIterator<Item = Pos<T>> + 'a) -> impl Iterator<Item = Pos<T>> + 'a {
@@ -67,6 +91,23 @@ impl<T: Copy + Num> Area<T> {
         self.rows() * self.cols()
     }
 
+    /// Returns the number of [`Pos<T>`]s on the boundary of this [`Area<T>`].
+    #[inline]
+    pub fn perimeter(&self) -> T {
+        let (rows, cols) = (self.rows(), self.cols());
+        if rows == T::one() || cols == T::one() {
+            return rows * cols;
+        }
+        (rows + cols - T::one() - T::one()) * (T::one() + T::one())
+    }
+
+    /// Returns the centre [`Pos<T>`] of this [`Area<T>`], rounded using the division of `T`.
+    #[inline]
+    pub fn center(&self) -> Pos<T> {
+        let two = T::one() + T::one();
+        Pos { x: (self.min_x + self.max_x) / two, y: (self.min_y + self.max_y) / two }
+    }
+
     /// Returns whether a [`Pos<T>`] is on the boundary of this [`Area<T>`].
     #[inline]
     pub fn on_boundary(&self, pos: &Pos<T>) -> bool {
@@ -96,6 +137,167 @@ impl<T: Copy + Num> Area<T> {
     pub fn wrap(&self, pos: &Pos<T>) -> Pos<T> {
         pos.modulo(&Pos { x: self.max_x - self.min_x, y: self.max_y - self.min_y })
     }
+
+    /// Returns this [`Area<T>`] shifted by an offset.
+    #[inline]
+    pub fn translate(&self, offset: &Pos<T>) -> Self {
+        Self {
+            max_x: self.max_x + offset.x,
+            max_y: self.max_y + offset.y,
+            min_x: self.min_x + offset.x,
+            min_y: self.min_y + offset.y,
+        }
+    }
+}
+
+impl<T: Copy + Num + Euclid> Area<T> {
+    /// Returns the [`Pos<T>`] wrapped toroidally into this [`Area<T>`], so stepping past one edge re-enters from the opposite edge.
+    #[inline]
+    pub fn wrap_pos(&self, pos: &Pos<T>) -> Pos<T> {
+        let offset = Pos { x: pos.x - self.min_x, y: pos.y - self.min_y }.rem_euclid(&Pos { x: self.cols(), y: self.rows() });
+        Pos { x: self.min_x + offset.x, y: self.min_y + offset.y }
+    }
+}
+
+impl<T: Copy + Num + PartialOrd> Area<T> {
+    /// Returns a square [`Area<T>`] which extends `radius` in every direction from a centre [`Pos<T>`].
+    #[inline]
+    pub fn from_center_and_radius(center: &Pos<T>, radius: T) -> Result<Self, AreaBoundaryError> {
+        Self::new(center.x + radius, center.y + radius, center.x - radius, center.y - radius)
+    }
+
+    /// Returns an iterator over the [`Pos<T>`]s on the boundary of this [`Area<T>`], each yielded once.
+    /// The bottom row is yielded first, then the top row, then the left and right columns between them.
+    pub fn iter_boundary(&self) -> impl Iterator<Item = Pos<T>> {
+        let bottom = Self { max_y: self.min_y, ..*self };
+        let top = Some(Self { min_y: self.max_y, ..*self }).filter(|_| self.max_y > self.min_y);
+        let inner = if self.max_y > self.min_y + T::one() {
+            Some(Self { max_y: self.max_y - T::one(), min_y: self.min_y + T::one(), ..*self })
+        } else {
+            None
+        };
+        let left = inner.map(|inner| Self { max_x: inner.min_x, ..inner });
+        let right = inner.map(|inner| Self { min_x: inner.max_x, ..inner }).filter(|_| self.max_x > self.min_x);
+        [Some(bottom), top, left, right].into_iter().flatten().flatten()
+    }
+
+    /// Returns an iterator over the [`Pos<T>`]s in a row of this [`Area<T>`], or [`None`] if `y` is outside the area.
+    #[inline]
+    pub fn row_iter(&self, y: T) -> Option<impl Iterator<Item = Pos<T>>> {
+        if y < self.min_y || y > self.max_y {
+            return None;
+        }
+        Some(Self { max_y: y, min_y: y, ..*self }.into_iter())
+    }
+
+    /// Returns an iterator over the [`Pos<T>`]s in a column of this [`Area<T>`], or [`None`] if `x` is outside the area.
+    #[inline]
+    pub fn col_iter(&self, x: T) -> Option<impl Iterator<Item = Pos<T>>> {
+        if x < self.min_x || x > self.max_x {
+            return None;
+        }
+        Some(Self { max_x: x, min_x: x, ..*self }.into_iter())
+    }
+
+    /// Returns this [`Area<T>`] reduced by a margin on every side, or [`None`] if nothing would remain.
+    #[inline]
+    pub fn shrink(&self, margin: T) -> Option<Self> {
+        let two = T::one() + T::one();
+        if margin > (self.max_x - self.min_x) / two || margin > (self.max_y - self.min_y) / two {
+            return None;
+        }
+        Some(Self { max_x: self.max_x - margin, max_y: self.max_y - margin, min_x: self.min_x + margin, min_y: self.min_y + margin })
+    }
+
+    /// Splits this [`Area<T>`] into non-overlapping tiles of the same size, ordered by Y then X.
+    /// Returns [`None`] if the tile dimensions are not positive or do not evenly divide this [`Area<T>`].
+    pub fn split_into_tiles(&self, tile_width: T, tile_height: T) -> Option<Vec<Self>> {
+        if tile_width <= T::zero() || tile_height <= T::zero() {
+            return None;
+        }
+        if !(self.cols() % tile_width).is_zero() || !(self.rows() % tile_height).is_zero() {
+            return None;
+        }
+
+        let mut result = Vec::new();
+        let mut y = self.min_y;
+        while y <= self.max_y {
+            let mut x = self.min_x;
+            while x <= self.max_x {
+                result.push(Self { max_x: x + tile_width - T::one(), max_y: y + tile_height - T::one(), min_x: x, min_y: y });
+                x = x + tile_width;
+            }
+            y = y + tile_height;
+        }
+        Some(result)
+    }
+
+    /// Splits this [`Area<T>`] into top left, top right, bottom left and bottom right quadrants, or [`None`] if it is narrower or shorter than 2.
+    /// The centre row and column are excluded when the area has an odd number of rows or columns.
+    pub fn quadrants(&self) -> Option<[Self; 4]> {
+        let two = T::one() + T::one();
+        if self.cols() < two || self.rows() < two {
+            return None;
+        }
+
+        let (half_w, half_h) = (self.cols() / two, self.rows() / two);
+        let (left_x, right_x) = (self.min_x + half_w - T::one(), self.max_x - half_w + T::one());
+        let (bottom_y, top_y) = (self.min_y + half_h - T::one(), self.max_y - half_h + T::one());
+        Some([
+            Self { max_x: left_x, min_y: top_y, ..*self },
+            Self { min_x: right_x, min_y: top_y, ..*self },
+            Self { max_x: left_x, max_y: bottom_y, ..*self },
+            Self { min_x: right_x, max_y: bottom_y, ..*self },
+        ])
+    }
+
+    /// Splits this [`Area<T>`] into a top half with the rows above `y` and a bottom half with the rows up to and including `y`.
+    /// The split row belongs to the bottom half only, so the halves do not overlap.
+    /// Returns [`None`] if `y` is outside the area. Splitting at the top row also returns [`None`]: every row would be in
+    /// the bottom half, and an [`Area<T>`] always holds at least one position, so there is no empty top half to return.
+    pub fn split_horizontal(&self, y: T) -> Option<(Self, Self)> {
+        if y < self.min_y || y >= self.max_y {
+            return None;
+        }
+        Some((Self { min_y: y + T::one(), ..*self }, Self { max_y: y, ..*self }))
+    }
+
+    /// Splits this [`Area<T>`] into a left half with the columns up to and including `x` and a right half with the columns right of `x`.
+    /// The split column belongs to the left half only, so the halves do not overlap.
+    /// Returns [`None`] if `x` is outside the area. Splitting at the rightmost column also returns [`None`]: every column would be in
+    /// the left half, and an [`Area<T>`] always holds at least one position, so there is no empty right half to return.
+    pub fn split_vertical(&self, x: T) -> Option<(Self, Self)> {
+        if x < self.min_x || x >= self.max_x {
+            return None;
+        }
+        Some((Self { max_x: x, ..*self }, Self { min_x: x + T::one(), ..*self }))
+    }
+}
+
+impl<T: Copy + Num + NumCast> Area<T> {
+    /// Returns `n` [`Pos<T>`]s evenly spaced along the boundary, going clockwise from the top left corner.
+    /// Sample `k` is placed `k * perimeter / n` steps along the boundary, so `n == 4` returns the corners of a square.
+    pub fn sample_boundary(&self, n: usize) -> Vec<Pos<T>> {
+        let (width, height) = ((self.max_x - self.min_x).to_usize().unwrap_or(0), (self.max_y - self.min_y).to_usize().unwrap_or(0));
+        let sides = [
+            (self.top_left(), Direction::Right, width),
+            (self.top_right(), Direction::Down, height),
+            (self.bottom_right(), Direction::Left, width),
+            (self.bottom_left(), Direction::Up, height),
+        ];
+
+        let perimeter = 2 * (width + height);
+        (0..n).map(|k| {
+            let mut offset = k * perimeter / n;
+            for (corner, direction, length) in sides {
+                if offset < length {
+                    return T::from(offset).map_or(corner, |offset| corner.dest(offset, direction));
+                }
+                offset -= length;
+            }
+            self.top_left()
+        }).collect()
+    }
 }
 
 impl<T: Copy + PartialOrd> Area<T> {
@@ -108,7 +310,81 @@ impl<T: Copy + PartialOrd> Area<T> {
     }
 }
 
+impl<T: Copy + Ord> Area<T> {
+    /// Returns the smallest [`Area<T>`] containing all the [`Pos<T>`]s, or [`None`] if there are none.
+    pub fn from_positions(pos: impl IntoIterator<Item = Pos<T>>) -> Option<Self> {
+        let mut pos = pos.into_iter();
+        let first = pos.next()?;
+        Some(pos.fold(Self { max_x: first.x, max_y: first.y, min_x: first.x, min_y: first.y }, |area, p| Self {
+            max_x: area.max_x.max(p.x),
+            max_y: area.max_y.max(p.y),
+            min_x: area.min_x.min(p.x),
+            min_y: area.min_y.min(p.y),
+        }))
+    }
+
+    /// Returns the [`Area<T>`] covered by both this [`Area<T>`] and another [`Area<T>`], or [`None`] if they do not overlap.
+    pub fn intersection(&self, other: &Self) -> Option<Self> {
+        let (min_x, max_x) = (self.min_x.max(other.min_x), self.max_x.min(other.max_x));
+        let (min_y, max_y) = (self.min_y.max(other.min_y), self.max_y.min(other.max_y));
+        if min_x > max_x || min_y > max_y {
+            return None;
+        }
+        Some(Self { max_x, max_y, min_x, min_y })
+    }
+
+    /// Returns the smallest [`Area<T>`] containing both this [`Area<T>`] and another [`Area<T>`].
+    #[inline]
+    pub fn union(&self, other: &Self) -> Self {
+        Self {
+            max_x: self.max_x.max(other.max_x),
+            max_y: self.max_y.max(other.max_y),
+            min_x: self.min_x.min(other.min_x),
+            min_y: self.min_y.min(other.min_y),
+        }
+    }
+
+    /// Returns a [`Pos<T>`] with X and Y clamped into this [`Area<T>`].
+    #[inline]
+    pub fn clamp_pos(&self, pos: &Pos<T>) -> Pos<T> {
+        Pos::clamp(*pos, self)
+    }
+}
+
+#[cfg(feature = "rand")]
+impl<T: Copy + PartialOrd + SampleUniform> Area<T> {
+    /// Returns a random sub [`Area<T>`] with both corners inside this [`Area<T>`].
+    /// Every sub area is equally likely to be returned.
+    pub fn random_subarea<R: RngCore>(&self, rng: &mut R) -> Self {
+        let (min_x, max_x) = Self::random_ordered_pair(rng, self.min_x, self.max_x);
+        let (min_y, max_y) = Self::random_ordered_pair(rng, self.min_y, self.max_y);
+        Self { max_x, max_y, min_x, min_y }
+    }
+
+    /// Returns a uniformly random pair `(a, b)` with `min <= a <= b <= max`.
+    fn random_ordered_pair<R: RngCore>(rng: &mut R, min: T, max: T) -> (T, T) {
+        loop {
+            let (a, b) = (rng.gen_range(min..=max), rng.gen_range(min..=max));
+            if a <= b {
+                return (a, b);
+            }
+        }
+    }
+}
+
 impl<T: Copy> Area<T> {
+    /// Returns the [`Interval<T>`] of valid y values.
+    #[inline]
+    pub fn row_range(&self) -> Interval<T> {
+        Interval { start: self.min_y, end: self.max_y }
+    }
+
+    /// Returns the [`Interval<T>`] of valid x values.
+    #[inline]
+    pub fn col_range(&self) -> Interval<T> {
+        Interval { start: self.min_x, end: self.max_x }
+    }
+
     /// Returns the top left [`Pos<T>`].
     #[inline]
     pub fn top_left(&self) -> Pos<T> {
@@ -148,10 +424,26 @@ impl<T: Copy + Num + CheckedAdd + CheckedSub + PartialOrd> Area<T> {
             .filter_map(move |dir| pos.checked_dest(distance, dir))
             .filter(|pos| self.has(pos))
     }
+
+    /// Returns this [`Area<T>`] grown by a margin on every side, or [`None`] if arithmetic error.
+    #[inline]
+    pub fn expand(&self, margin: T) -> Option<Self> {
+        Some(Self {
+            max_x: self.max_x.checked_add(&margin)?,
+            max_y: self.max_y.checked_add(&margin)?,
+            min_x: self.min_x.checked_sub(&margin)?,
+            min_y: self.min_y.checked_sub(&margin)?,
+        })
+    }
 }
 
 #[cfg(test)]
 mod test {
+    #[cfg(feature = "rand")]
+    use rand::rngs::SmallRng;
+    #[cfg(feature = "rand")]
+    use rand::SeedableRng;
+    use std::collections::HashSet;
     use super::*;
 
     #[test]
@@ -165,6 +457,18 @@ mod test {
         assert_eq!(sut, AreaIterator { area, current_x: -5, current_y: -10, });
     }
 
+    #[test]
+    fn test_into_iter_ref() {
+        let area = Area { max_x: 2, max_y: 1, min_x: -1, min_y: 0 };
+        let mut sut = Vec::new();
+        for p in &area {
+            sut.push(p);
+        }
+        assert_eq!(sut, area.into_iter().collect::<Vec<_>>());
+        assert_eq!((&area).into_iter(), AreaIterator { area, current_x: -1, current_y: 0 });
+        assert_eq!(area.size(), 8);
+    }
+
     #[test]
     fn test_new() {
         let sut = Area::new(10, 10, 0, 0);
@@ -187,6 +491,16 @@ mod test {
         assert!(!sut.has(&Pos { x: -1, y: 10 }));
     }
 
+    #[test]
+    fn test_contains_area() {
+        let sut = Area { max_x: 10, max_y: 10, min_x: 0, min_y: 0 };
+        assert!(sut.contains_area(&sut));
+        assert!(sut.contains_area(&Area { max_x: 5, max_y: 5, min_x: 1, min_y: 1 }));
+        assert!(sut.contains_area(&Area { max_x: 10, max_y: 0, min_x: 10, min_y: 0 }));
+        assert!(!sut.contains_area(&Area { max_x: 11, max_y: 5, min_x: 1, min_y: 1 }));
+        assert!(!sut.contains_area(&Area { max_x: 5, max_y: 5, min_x: -1, min_y: -1 }));
+    }
+
     #[test]
     fn test_filter_pos() {
         let area = Area { max_x: 10, max_y: 10, min_x: 0, min_y: 0 };
@@ -240,6 +554,37 @@ mod test {
         assert_eq!(sut.size(), 441);
     }
 
+    #[test]
+    fn test_perimeter() {
+        let sut = Area { max_x: 0, max_y: 0, min_x: 0, min_y: 0 };
+        assert_eq!(sut.perimeter(), 1);
+
+        let sut = Area { max_x: 9, max_y: 0, min_x: 0, min_y: 0 };
+        assert_eq!(sut.perimeter(), 10);
+
+        let sut = Area { max_x: 0, max_y: 4, min_x: 0, min_y: -4 };
+        assert_eq!(sut.perimeter(), 9);
+
+        let sut = Area { max_x: 1, max_y: 1, min_x: 0, min_y: 0 };
+        assert_eq!(sut.perimeter(), 4);
+
+        let sut = Area { max_x: 10, max_y: 10, min_x: 0, min_y: 0 };
+        assert_eq!(sut.perimeter(), 40);
+
+        let sut = Area { max_x: 5, max_y: 2, min_x: -5, min_y: 0 };
+        assert_eq!(sut.perimeter(), 24);
+        assert_eq!(sut.perimeter() as usize, sut.into_iter().filter(|p| sut.on_boundary(p)).count());
+    }
+
+    #[test]
+    fn test_center() {
+        assert_eq!(Area { max_x: 0, max_y: 0, min_x: 0, min_y: 0 }.center(), Pos { x: 0, y: 0 });
+        assert_eq!(Area { max_x: 1, max_y: 1, min_x: 0, min_y: 0 }.center(), Pos { x: 0, y: 0 });
+        assert_eq!(Area { max_x: 4, max_y: 2, min_x: 0, min_y: 0 }.center(), Pos { x: 2, y: 1 });
+        assert_eq!(Area { max_x: 3, max_y: 9, min_x: -5, min_y: -1 }.center(), Pos { x: -1, y: 4 });
+        assert_eq!(Area { max_x: 1.0, max_y: 1.0, min_x: 0.0, min_y: 0.0 }.center(), Pos { x: 0.5, y: 0.5 });
+    }
+
     #[test]
     fn test_on_boundary() {
         let sut = Area { max_x: 10, max_y: 10, min_x: 0, min_y: 0 };
@@ -303,6 +648,293 @@ mod test {
         assert_eq!(sut.wrap(&Pos { x: -2, y: -3 }), Pos { x: 8, y: 12 });
     }
 
+    #[test]
+    fn test_wrap_pos() {
+        let sut = Area { max_x: 5, max_y: 3, min_x: -2, min_y: 0 };
+        assert_eq!(sut.wrap_pos(&Pos { x: 1, y: 2 }), Pos { x: 1, y: 2 });
+        assert_eq!(sut.wrap_pos(&Pos { x: 5, y: 3 }), Pos { x: 5, y: 3 });
+        assert_eq!(sut.wrap_pos(&Pos { x: 6, y: 1 }), Pos { x: -2, y: 1 });
+        assert_eq!(sut.wrap_pos(&Pos { x: -3, y: 1 }), Pos { x: 5, y: 1 });
+        assert_eq!(sut.wrap_pos(&Pos { x: 0, y: 4 }), Pos { x: 0, y: 0 });
+        assert_eq!(sut.wrap_pos(&Pos { x: 0, y: -1 }), Pos { x: 0, y: 3 });
+        assert_eq!(sut.wrap_pos(&Pos { x: 6 + 8 * 3, y: 4 + 4 * 5 }), Pos { x: -2, y: 0 });
+        assert_eq!(sut.wrap_pos(&Pos { x: -3 - 8 * 7, y: -1 - 4 * 9 }), Pos { x: 5, y: 3 });
+
+        let sut: Area<usize> = Area { max_x: 10, max_y: 10, min_x: 0, min_y: 0 };
+        assert_eq!(sut.wrap_pos(&Pos { x: 11, y: 32 }), Pos { x: 0, y: 10 });
+    }
+
+    #[test]
+    fn test_translate() {
+        let sut = Area { max_x: 5, max_y: 3, min_x: -2, min_y: 0 };
+        assert_eq!(sut.translate(&Pos { x: 0, y: 0 }), sut);
+        assert_eq!(sut.translate(&Pos { x: 3, y: 4 }), Area { max_x: 8, max_y: 7, min_x: 1, min_y: 4 });
+        assert_eq!(sut.translate(&Pos { x: -5, y: -1 }), Area { max_x: 0, max_y: 2, min_x: -7, min_y: -1 });
+        assert_eq!(sut.translate(&Pos { x: 2, y: -2 }).size(), sut.size());
+
+        let sut: Area<usize> = Area { max_x: 4, max_y: 2, min_x: 1, min_y: 0 };
+        assert_eq!(sut.translate(&Pos { x: 10, y: 1 }), Area { max_x: 14, max_y: 3, min_x: 11, min_y: 1 });
+    }
+
+    #[test]
+    fn test_from_center_and_radius() {
+        let sut = Area::from_center_and_radius(&Pos { x: 0, y: 0 }, 2).unwrap();
+        assert_eq!(sut, Area { max_x: 2, max_y: 2, min_x: -2, min_y: -2 });
+        assert_eq!(sut.size(), 25);
+        assert!(sut.has(&Pos { x: 0, y: 0 }));
+
+        let sut = Area::from_center_and_radius(&Pos { x: 10, y: -3 }, 0).unwrap();
+        assert_eq!(sut.size(), 1);
+        assert!(sut.has(&Pos { x: 10, y: -3 }));
+
+        let sut = Area::from_center_and_radius(&Pos { x: 5, y: 5 }, 4).unwrap();
+        assert_eq!(sut.size(), 81);
+
+        assert!(Area::from_center_and_radius(&Pos { x: 0, y: 0 }, -1).is_err());
+    }
+
+    #[test]
+    fn test_expand() {
+        let area = Area { max_x: 5, max_y: 3, min_x: 0, min_y: -1 };
+        assert_eq!(area.expand(0), Some(area));
+        assert_eq!(area.expand(2), Some(Area { max_x: 7, max_y: 5, min_x: -2, min_y: -3 }));
+        assert_eq!(area.expand(2).unwrap().size(), 10 * 9);
+        assert!(area.expand(1).unwrap().contains_area(&area));
+        assert_eq!(area.expand(i32::MAX), None);
+
+        let area: Area<usize> = Area { max_x: 4, max_y: 2, min_x: 0, min_y: 0 };
+        assert_eq!(area.expand(1), None);
+        let area: Area<usize> = Area { max_x: 4, max_y: 3, min_x: 1, min_y: 2 };
+        assert_eq!(area.expand(1), Some(Area { max_x: 5, max_y: 4, min_x: 0, min_y: 1 }));
+        assert_eq!(area.expand(3), None);
+    }
+
+    #[test]
+    fn test_shrink() {
+        let area = Area { max_x: 5, max_y: 3, min_x: 0, min_y: -1 };
+        assert_eq!(area.shrink(0), Some(area));
+        assert_eq!(area.shrink(1), Some(Area { max_x: 4, max_y: 2, min_x: 1, min_y: 0 }));
+        assert_eq!(area.shrink(2), Some(Area { max_x: 3, max_y: 1, min_x: 2, min_y: 1 }));
+        assert_eq!(area.shrink(3), None);
+        assert_eq!(area.expand(4).unwrap().shrink(4), Some(area));
+        assert_eq!(area.shrink(-1), area.expand(1));
+
+        let area: Area<usize> = Area { max_x: 2, max_y: 2, min_x: 0, min_y: 0 };
+        assert_eq!(area.shrink(1), Some(Area { max_x: 1, max_y: 1, min_x: 1, min_y: 1 }));
+        assert_eq!(area.shrink(2), None);
+        assert_eq!(area.shrink(3), None);
+    }
+
+    #[test]
+    fn test_split_into_tiles() {
+        let area = Area { max_x: 5, max_y: 3, min_x: 0, min_y: 0 };
+        let sut = area.split_into_tiles(2, 2).unwrap();
+        assert_eq!(sut.len(), 6);
+        assert_eq!(sut[0], Area { max_x: 1, max_y: 1, min_x: 0, min_y: 0 });
+        assert_eq!(sut[2], Area { max_x: 5, max_y: 1, min_x: 4, min_y: 0 });
+        assert_eq!(sut[3], Area { max_x: 1, max_y: 3, min_x: 0, min_y: 2 });
+        assert_eq!(sut.iter().map(|tile| tile.size()).sum::<i32>(), area.size());
+        assert!(area.into_iter().all(|p| sut.iter().filter(|tile| tile.has(&p)).count() == 1));
+
+        let area = Area { max_x: 2, max_y: 1, min_x: -3, min_y: -4 };
+        let sut = area.split_into_tiles(3, 6).unwrap();
+        assert_eq!(sut, vec![Area { max_x: -1, max_y: 1, min_x: -3, min_y: -4 }, Area { max_x: 2, max_y: 1, min_x: 0, min_y: -4 }]);
+        assert_eq!(area.split_into_tiles(6, 6), Some(vec![area]));
+
+        assert!(area.split_into_tiles(4, 6).is_none());
+        assert!(area.split_into_tiles(3, 4).is_none());
+        assert!(area.split_into_tiles(0, 6).is_none());
+        assert!(area.split_into_tiles(-3, 6).is_none());
+    }
+
+    #[test]
+    fn test_quadrants() {
+        let area = Area { max_x: 10, max_y: 6, min_x: 0, min_y: 0 };
+        let sut = area.quadrants().unwrap();
+        assert_eq!(sut, [
+            Area { max_x: 4, max_y: 6, min_x: 0, min_y: 4 },
+            Area { max_x: 10, max_y: 6, min_x: 6, min_y: 4 },
+            Area { max_x: 4, max_y: 2, min_x: 0, min_y: 0 },
+            Area { max_x: 10, max_y: 2, min_x: 6, min_y: 0 },
+        ]);
+        let covered: HashSet<_> = sut.iter().flat_map(|q| q.into_iter()).collect();
+        assert_eq!(covered.len() as i32, area.size() - area.cols() - area.rows() + 1);
+        assert!(covered.iter().all(|p| p.x != 5 && p.y != 3));
+
+        let area = Area { max_x: 1, max_y: 2, min_x: -2, min_y: -1 };
+        let sut = area.quadrants().unwrap();
+        assert_eq!(sut[0], Area { max_x: -1, max_y: 2, min_x: -2, min_y: 1 });
+        assert_eq!(sut[3], Area { max_x: 1, max_y: 0, min_x: 0, min_y: -1 });
+        let covered: HashSet<_> = sut.iter().flat_map(|q| q.into_iter()).collect();
+        assert_eq!(covered.len() as i32, area.size());
+
+        assert!(Area { max_x: 0, max_y: 5, min_x: 0, min_y: 0 }.quadrants().is_none());
+        assert!(Area { max_x: 5, max_y: 0, min_x: 0, min_y: 0 }.quadrants().is_none());
+    }
+
+    #[test]
+    fn test_split_horizontal() {
+        let area = Area { max_x: 4, max_y: 6, min_x: 0, min_y: -2 };
+        let (top, bottom) = area.split_horizontal(3).unwrap();
+        assert_eq!(top, Area { max_x: 4, max_y: 6, min_x: 0, min_y: 4 });
+        assert_eq!(bottom, Area { max_x: 4, max_y: 3, min_x: 0, min_y: -2 });
+        assert!(area.into_iter().all(|p| top.has(&p) != bottom.has(&p)));
+        assert!(area.row_iter(3).unwrap().all(|p| bottom.has(&p) && !top.has(&p)));
+        assert_eq!(top.size() + bottom.size(), area.size());
+
+        let (top, bottom) = area.split_horizontal(-2).unwrap();
+        assert_eq!(top, Area { max_x: 4, max_y: 6, min_x: 0, min_y: -1 });
+        assert_eq!(bottom, Area { max_x: 4, max_y: -2, min_x: 0, min_y: -2 });
+
+        assert!(area.split_horizontal(6).is_none());
+        assert_eq!(area.split_horizontal(5).map(|(top, _)| top.rows()), Some(1));
+        assert!(area.split_horizontal(7).is_none());
+        assert!(area.split_horizontal(-3).is_none());
+
+        let area = Area { max_x: 3, max_y: 7, min_x: 0, min_y: 0 };
+        let (top, bottom) = area.split_horizontal(3).unwrap();
+        assert_eq!(top.size(), bottom.size());
+        assert_eq!((top.rows(), bottom.rows()), (4, 4));
+
+        let (top, bottom) = area.split_horizontal(1).unwrap();
+        assert_eq!((top.rows(), bottom.rows()), (6, 2));
+        assert!(area.into_iter().all(|p| top.has(&p) != bottom.has(&p)));
+
+        let area: Area<usize> = Area { max_x: 2, max_y: 1, min_x: 0, min_y: 0 };
+        assert_eq!(area.split_horizontal(0), Some((Area { max_x: 2, max_y: 1, min_x: 0, min_y: 1 }, Area { max_x: 2, max_y: 0, min_x: 0, min_y: 0 })));
+    }
+
+    #[test]
+    fn test_split_vertical() {
+        let area = Area { max_x: 4, max_y: 6, min_x: 0, min_y: -2 };
+        let (left, right) = area.split_vertical(1).unwrap();
+        assert_eq!(left, Area { max_x: 1, max_y: 6, min_x: 0, min_y: -2 });
+        assert_eq!(right, Area { max_x: 4, max_y: 6, min_x: 2, min_y: -2 });
+        assert!(area.into_iter().all(|p| left.has(&p) != right.has(&p)));
+        assert!(area.col_iter(1).unwrap().all(|p| left.has(&p) && !right.has(&p)));
+        assert_eq!(left.size() + right.size(), area.size());
+
+        let (left, right) = area.split_vertical(3).unwrap();
+        assert_eq!(left, Area { max_x: 3, max_y: 6, min_x: 0, min_y: -2 });
+        assert_eq!(right, Area { max_x: 4, max_y: 6, min_x: 4, min_y: -2 });
+
+        assert!(area.split_vertical(4).is_none());
+        assert_eq!(area.split_vertical(3).map(|(_, right)| right.cols()), Some(1));
+        assert!(area.split_vertical(5).is_none());
+        assert!(area.split_vertical(-1).is_none());
+
+        let area = Area { max_x: 5, max_y: 3, min_x: -4, min_y: 0 };
+        let (left, right) = area.split_vertical(0).unwrap();
+        assert_eq!(left.size(), right.size());
+        assert_eq!((left.cols(), right.cols()), (5, 5));
+
+        let (left, right) = area.split_vertical(3).unwrap();
+        assert_eq!((left.cols(), right.cols()), (8, 2));
+        assert!(area.into_iter().all(|p| left.has(&p) != right.has(&p)));
+    }
+
+    #[test]
+    fn test_iter_boundary() {
+        let area = Area { max_x: 3, max_y: 2, min_x: 0, min_y: -1 };
+        let sut: Vec<_> = area.iter_boundary().collect();
+        assert_eq!(sut, vec![
+            Pos { x: 0, y: -1 },
+            Pos { x: 1, y: -1 },
+            Pos { x: 2, y: -1 },
+            Pos { x: 3, y: -1 },
+            Pos { x: 0, y: 2 },
+            Pos { x: 1, y: 2 },
+            Pos { x: 2, y: 2 },
+            Pos { x: 3, y: 2 },
+            Pos { x: 0, y: 0 },
+            Pos { x: 0, y: 1 },
+            Pos { x: 3, y: 0 },
+            Pos { x: 3, y: 1 },
+        ]);
+        assert_eq!(sut.len() as i32, 2 * (area.rows() + area.cols() - 2));
+
+        for area in [
+            Area { max_x: 0, max_y: 0, min_x: 0, min_y: 0 },
+            Area { max_x: 4, max_y: 0, min_x: 0, min_y: 0 },
+            Area { max_x: 0, max_y: 4, min_x: 0, min_y: 0 },
+            Area { max_x: 1, max_y: 1, min_x: 0, min_y: 0 },
+            Area { max_x: 1, max_y: 5, min_x: 0, min_y: 0 },
+            Area { max_x: 7, max_y: 3, min_x: -2, min_y: -6 },
+        ] {
+            let sut: Vec<_> = area.iter_boundary().collect();
+            assert_eq!(sut.len() as i32, area.perimeter());
+            assert!(sut.iter().all(|p| area.on_boundary(p)));
+            assert_eq!(sut.len(), area.into_iter().filter(|p| area.on_boundary(p)).count());
+        }
+
+        let area: Area<usize> = Area { max_x: 2, max_y: 0, min_x: 0, min_y: 0 };
+        assert_eq!(area.iter_boundary().count(), 3);
+    }
+
+    #[test]
+    fn test_row_iter() {
+        let area = Area { max_x: 3, max_y: 2, min_x: -1, min_y: 0 };
+        let sut: Vec<_> = area.row_iter(1).unwrap().collect();
+        assert_eq!(sut, vec![Pos { x: -1, y: 1 }, Pos { x: 0, y: 1 }, Pos { x: 1, y: 1 }, Pos { x: 2, y: 1 }, Pos { x: 3, y: 1 }]);
+        assert_eq!(area.row_iter(0).unwrap().count() as i32, area.cols());
+        assert!(area.row_iter(2).unwrap().all(|p| p.y == 2));
+        assert!(area.row_iter(3).is_none());
+        assert!(area.row_iter(-1).is_none());
+    }
+
+    #[test]
+    fn test_col_iter() {
+        let area = Area { max_x: 3, max_y: 2, min_x: -1, min_y: 0 };
+        let sut: Vec<_> = area.col_iter(-1).unwrap().collect();
+        assert_eq!(sut, vec![Pos { x: -1, y: 0 }, Pos { x: -1, y: 1 }, Pos { x: -1, y: 2 }]);
+        assert_eq!(area.col_iter(3).unwrap().count() as i32, area.rows());
+        assert!(area.col_iter(2).unwrap().all(|p| p.x == 2));
+        assert!(area.col_iter(4).is_none());
+        assert!(area.col_iter(-2).is_none());
+    }
+
+    #[test]
+    fn test_sample_boundary() {
+        let area = Area { max_x: 4, max_y: 4, min_x: 0, min_y: 0 };
+        assert_eq!(area.sample_boundary(4), vec![Pos { x: 0, y: 4 }, Pos { x: 4, y: 4 }, Pos { x: 4, y: 0 }, Pos { x: 0, y: 0 }]);
+
+        let area = Area { max_x: 4, max_y: 2, min_x: 0, min_y: 0 };
+        let sut = area.sample_boundary(8);
+        assert_eq!(sut, vec![
+            Pos { x: 0, y: 2 },
+            Pos { x: 1, y: 2 },
+            Pos { x: 3, y: 2 },
+            Pos { x: 4, y: 2 },
+            Pos { x: 4, y: 0 },
+            Pos { x: 3, y: 0 },
+            Pos { x: 1, y: 0 },
+            Pos { x: 0, y: 0 },
+        ]);
+
+        let sut = area.sample_boundary(6);
+        assert_eq!(sut, vec![Pos { x: 0, y: 2 }, Pos { x: 2, y: 2 }, Pos { x: 4, y: 2 }, Pos { x: 4, y: 0 }, Pos { x: 2, y: 0 }, Pos { x: 0, y: 0 }]);
+
+        let area = Area { max_x: 99, max_y: 1, min_x: 0, min_y: 0 };
+        let sut = area.sample_boundary(4);
+        assert_eq!(sut, vec![Pos { x: 0, y: 1 }, Pos { x: 50, y: 1 }, Pos { x: 99, y: 0 }, Pos { x: 49, y: 0 }]);
+
+        let area = Area { max_x: 7, max_y: 3, min_x: -7, min_y: -13 };
+        for n in 0..50 {
+            let sut = area.sample_boundary(n);
+            assert_eq!(sut.len(), n);
+            assert!(sut.iter().all(|p| area.on_boundary(p)));
+        }
+        assert!(area.sample_boundary(0).is_empty());
+
+        let area = Area { max_x: 2, max_y: 3, min_x: 2, min_y: 0 };
+        let sut = area.sample_boundary(3);
+        assert_eq!(sut, vec![Pos { x: 2, y: 3 }, Pos { x: 2, y: 1 }, Pos { x: 2, y: 1 }]);
+        assert_eq!(area.sample_boundary(10).len(), 10);
+
+        let area = Area { max_x: 2, max_y: 5, min_x: 2, min_y: 5 };
+        assert_eq!(area.sample_boundary(3), vec![Pos { x: 2, y: 5 }; 3]);
+    }
+
     #[test]
     fn test_from_pos() {
         let sut = Area::from_pos(&Pos { x: 0, y: 10 }, &Pos { x: 10, y: 0 }).unwrap();
@@ -327,6 +959,99 @@ mod test {
         assert!(sut.is_err());
     }
 
+    #[test]
+    fn test_from_positions() {
+        assert_eq!(Area::<i32>::from_positions([]), None);
+        assert_eq!(Area::from_positions([Pos { x: 3, y: -2 }]), Some(Area { max_x: 3, max_y: -2, min_x: 3, min_y: -2 }));
+
+        let pos = [Pos { x: 3, y: -2 }, Pos { x: -4, y: 0 }, Pos { x: 1, y: 7 }, Pos { x: 0, y: 0 }];
+        let sut = Area::from_positions(pos).unwrap();
+        assert_eq!(sut, Area { max_x: 3, max_y: 7, min_x: -4, min_y: -2 });
+        assert!(pos.iter().all(|p| sut.has(p)));
+        assert_eq!(Area::from_positions(sut), Some(sut));
+    }
+
+    #[test]
+    fn test_intersection() {
+        let area = Area { max_x: 5, max_y: 5, min_x: 0, min_y: 0 };
+        assert_eq!(area.intersection(&area), Some(area));
+        assert_eq!(area.intersection(&Area { max_x: 3, max_y: 4, min_x: 1, min_y: 2 }), Some(Area { max_x: 3, max_y: 4, min_x: 1, min_y: 2 }));
+        assert_eq!(area.intersection(&Area { max_x: 8, max_y: 2, min_x: 3, min_y: -4 }), Some(Area { max_x: 5, max_y: 2, min_x: 3, min_y: 0 }));
+        assert_eq!(area.intersection(&Area { max_x: 9, max_y: 9, min_x: 5, min_y: 5 }), Some(Area { max_x: 5, max_y: 5, min_x: 5, min_y: 5 }));
+        assert_eq!(area.intersection(&Area { max_x: 9, max_y: 3, min_x: 5, min_y: 1 }), Some(Area { max_x: 5, max_y: 3, min_x: 5, min_y: 1 }));
+        assert_eq!(area.intersection(&Area { max_x: 9, max_y: 9, min_x: 6, min_y: 0 }), None);
+        assert_eq!(area.intersection(&Area { max_x: 3, max_y: -1, min_x: 1, min_y: -5 }), None);
+    }
+
+    #[test]
+    fn test_union() {
+        let area = Area { max_x: 5, max_y: 5, min_x: 0, min_y: 0 };
+        assert_eq!(area.union(&area), area);
+        assert_eq!(area.union(&Area { max_x: 3, max_y: 4, min_x: 1, min_y: 2 }), area);
+        assert_eq!(Area { max_x: 3, max_y: 4, min_x: 1, min_y: 2 }.union(&area), area);
+
+        let other = Area { max_x: 12, max_y: -2, min_x: 8, min_y: -6 };
+        let sut = area.union(&other);
+        assert_eq!(sut, Area { max_x: 12, max_y: 5, min_x: 0, min_y: -6 });
+        assert!(sut.contains_area(&area) && sut.contains_area(&other));
+        assert!(sut.size() > area.size() + other.size());
+    }
+
+    #[test]
+    fn test_clamp_pos() {
+        let sut = Area { max_x: 5, max_y: 3, min_x: -2, min_y: 0 };
+        assert_eq!(sut.clamp_pos(&Pos { x: 1, y: 2 }), Pos { x: 1, y: 2 });
+        assert_eq!(sut.clamp_pos(&Pos { x: -10, y: 10 }), Pos { x: -2, y: 3 });
+        assert_eq!(sut.clamp_pos(&Pos { x: 7, y: 1 }), Pos { x: 5, y: 1 });
+    }
+
+    #[test]
+    #[cfg(feature = "rand")]
+    fn test_random_subarea() {
+        let mut rng = SmallRng::seed_from_u64(101);
+        let area = Area { max_x: 10, max_y: 5, min_x: -10, min_y: -5 };
+        for _ in 0..1000 {
+            let sut = area.random_subarea(&mut rng);
+            assert!(area.contains_area(&sut));
+            assert!(Area::new(sut.max_x, sut.max_y, sut.min_x, sut.min_y).is_ok());
+        }
+
+        let area = Area { max_x: 3, max_y: 3, min_x: 3, min_y: 3 };
+        assert_eq!(area.random_subarea(&mut rng), area);
+
+        let area = Area { max_x: 1, max_y: 0, min_x: 0, min_y: 0 };
+        let mut counts = [0; 3];
+        for _ in 0..3000 {
+            let sut = area.random_subarea(&mut rng);
+            counts[(sut.min_x + sut.max_x) as usize] += 1;
+        }
+        assert!(counts.iter().all(|&count| (800..1200).contains(&count)));
+    }
+
+    #[test]
+    fn test_row_range() {
+        let area = Area { max_x: 4, max_y: 6, min_x: 0, min_y: -2 };
+        let sut = area.row_range();
+        assert_eq!(sut, Interval { start: -2, end: 6 });
+        assert!(sut.contains(&-2));
+        assert!(sut.contains(&1));
+        assert!(sut.contains(&6));
+        assert!(!sut.contains(&-3));
+        assert!(!sut.contains(&7));
+    }
+
+    #[test]
+    fn test_col_range() {
+        let area = Area { max_x: 4, max_y: 6, min_x: 0, min_y: -2 };
+        let sut = area.col_range();
+        assert_eq!(sut, Interval { start: 0, end: 4 });
+        assert!(sut.contains(&0));
+        assert!(sut.contains(&2));
+        assert!(sut.contains(&4));
+        assert!(!sut.contains(&-1));
+        assert!(!sut.contains(&5));
+    }
+
     #[test]
     fn test_top_left() {
         let sut = Area { max_x: 10, max_y: 10, min_x: 0, min_y: 0 };
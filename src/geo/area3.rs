@@ -0,0 +1,97 @@
+use num::Num;
+use crate::geo::area::AreaBoundaryError;
+use crate::geo::area3_iter::Area3Iterator;
+use crate::geo::pos3::Pos3;
+
+/// A 3D axis aligned box.
+#[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
+pub struct Area3<T> {
+    pub max_x: T,
+    pub max_y: T,
+    pub max_z: T,
+    pub min_x: T,
+    pub min_y: T,
+    pub min_z: T,
+}
+
+impl<T: Copy + Num + PartialOrd> IntoIterator for Area3<T> {
+    type Item = Pos3<T>;
+    type IntoIter = Area3Iterator<T>;
+
+    #[inline]
+    fn into_iter(self) -> Self::IntoIter {
+        Self::IntoIter { area: self, current: Pos3 { x: self.min_x, y: self.min_y, z: self.min_z } }
+    }
+}
+
+impl<T: PartialOrd> Area3<T> {
+    /// Returns a new [`Area3<T>`].
+    pub fn new(max: Pos3<T>, min: Pos3<T>) -> Result<Self, AreaBoundaryError> {
+        if max.x < min.x || max.y < min.y || max.z < min.z {
+            return Err(AreaBoundaryError);
+        }
+        Ok(Self { max_x: max.x, max_y: max.y, max_z: max.z, min_x: min.x, min_y: min.y, min_z: min.z })
+    }
+
+    /// Checks whether a [`Pos3<T>`] is in this [`Area3<T>`].
+    #[inline]
+    pub fn has(&self, pos: &Pos3<T>) -> bool {
+        pos.x >= self.min_x && pos.x <= self.max_x
+            && pos.y >= self.min_y && pos.y <= self.max_y
+            && pos.z >= self.min_z && pos.z <= self.max_z
+    }
+}
+
+impl<T: Copy + Num> Area3<T> {
+    /// Returns the volume of this [`Area3<T>`].
+    #[inline]
+    pub fn size(&self) -> T {
+        (self.max_x - self.min_x + T::one()) * (self.max_y - self.min_y + T::one()) * (self.max_z - self.min_z + T::one())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_into_iter() {
+        let area = Area3 { max_x: 1, max_y: 2, max_z: 3, min_x: -1, min_y: -2, min_z: -3 };
+        let sut = area.into_iter();
+        assert_eq!(sut, Area3Iterator { area, current: Pos3 { x: -1, y: -2, z: -3 } });
+        assert_eq!(area.into_iter().count(), 105);
+    }
+
+    #[test]
+    fn test_new() {
+        let sut = Area3::new(Pos3 { x: 1, y: 2, z: 3 }, Pos3 { x: 0, y: 0, z: 0 });
+        assert_eq!(sut.unwrap(), Area3 { max_x: 1, max_y: 2, max_z: 3, min_x: 0, min_y: 0, min_z: 0 });
+
+        let sut = Area3::new(Pos3 { x: 1, y: 2, z: -1 }, Pos3 { x: 0, y: 0, z: 0 });
+        assert!(sut.is_err());
+    }
+
+    #[test]
+    fn test_has() {
+        let sut = Area3 { max_x: 3, max_y: 3, max_z: 3, min_x: 0, min_y: 0, min_z: 0 };
+        for x in [0, 3] {
+            for y in [0, 3] {
+                for z in [0, 3] {
+                    assert!(sut.has(&Pos3 { x, y, z }));
+                }
+            }
+        }
+        assert!(sut.has(&Pos3 { x: 1, y: 2, z: 1 }));
+        assert!(!sut.has(&Pos3 { x: 1, y: 2, z: 4 }));
+        assert!(!sut.has(&Pos3 { x: -1, y: 0, z: 0 }));
+    }
+
+    #[test]
+    fn test_size() {
+        let sut = Area3 { max_x: 3, max_y: 3, max_z: 3, min_x: 0, min_y: 0, min_z: 0 };
+        assert_eq!(sut.size(), 64);
+
+        let sut = Area3 { max_x: 0, max_y: 0, max_z: 0, min_x: 0, min_y: 0, min_z: 0 };
+        assert_eq!(sut.size(), 1);
+    }
+}
@@ -0,0 +1,64 @@
+use num::Num;
+use crate::geo::area3::Area3;
+use crate::geo::pos3::Pos3;
+
+/// Represents an iterator that iterates through all the [`Pos3<T>`]s inside an [`Area3<T>`].
+/// X varies the fastest and Z varies the slowest.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct Area3Iterator<T> {
+    pub(crate) area: Area3<T>,
+    pub(crate) current: Pos3<T>,
+}
+
+impl<T: Copy + Num + PartialOrd> Iterator for Area3Iterator<T> {
+    type Item = Pos3<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.current.z > self.area.max_z {
+            return None;
+        }
+
+        let result = self.current;
+        if self.current.x < self.area.max_x {
+            self.current.x = self.current.x + T::one();
+        } else if self.current.y < self.area.max_y {
+            self.current.x = self.area.min_x;
+            self.current.y = self.current.y + T::one();
+        } else {
+            self.current.x = self.area.min_x;
+            self.current.y = self.area.min_y;
+            self.current.z = self.current.z + T::one();
+        }
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_iter() {
+        let area = Area3 { max_x: 1, max_y: 1, max_z: 1, min_x: 0, min_y: 0, min_z: 0 };
+        let sut: Vec<_> = area.into_iter().collect();
+        assert_eq!(sut, vec![
+            Pos3 { x: 0, y: 0, z: 0 },
+            Pos3 { x: 1, y: 0, z: 0 },
+            Pos3 { x: 0, y: 1, z: 0 },
+            Pos3 { x: 1, y: 1, z: 0 },
+            Pos3 { x: 0, y: 0, z: 1 },
+            Pos3 { x: 1, y: 0, z: 1 },
+            Pos3 { x: 0, y: 1, z: 1 },
+            Pos3 { x: 1, y: 1, z: 1 },
+        ]);
+
+        let area = Area3 { max_x: 4, max_y: 0, max_z: 2, min_x: 2, min_y: -3, min_z: 2 };
+        let sut: Vec<_> = area.into_iter().collect();
+        assert_eq!(sut.len(), 12);
+        assert!(sut.iter().all(|p| area.has(p)));
+
+        let area = Area3 { max_x: 0, max_y: 0, max_z: 0, min_x: 0, min_y: 0, min_z: 0 };
+        let sut: Vec<_> = area.into_iter().collect();
+        assert_eq!(sut, vec![Pos3 { x: 0, y: 0, z: 0 }]);
+    }
+}
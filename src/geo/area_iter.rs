@@ -58,5 +58,9 @@ mod test {
         let sut: Vec<Pos<_>> = area.into_iter().collect();
         assert_eq!(sut.len(), 1);
         assert_eq!(sut[0], Pos { x: 0, y: 0 });
+
+        let area = Area { max_x: i32::MAX, max_y: 0, min_x: i32::MIN, min_y: 0 };
+        let sut: Vec<Pos<_>> = area.into_iter().take(3).collect();
+        assert_eq!(sut, vec![Pos { x: i32::MIN, y: 0 }, Pos { x: i32::MIN + 1, y: 0 }, Pos { x: i32::MIN + 2, y: 0 }]);
     }
 }
@@ -1,8 +1,21 @@
 use core::cmp::Ordering;
 use core::fmt::{Display, Formatter};
-use num::{CheckedAdd, CheckedSub, Num};
+use core::str::FromStr;
+#[cfg(feature = "std")]
+use std::collections::HashSet;
+#[cfg(feature = "std")]
+use std::hash::Hash;
+use num::{CheckedAdd, CheckedSub, Num, Signed};
 use crate::geo::pos::Pos;
 
+/// An error returned when parsing a [`Direction`] fails.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseDirectionError;
+
+/// An error returned when a [`Pos<i32>`] is not the unit step of any [`Direction`].
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct DirectionDeltaError;
+
 /// Represents the directions in a 2D grid.
 #[derive(Clone, Copy, Debug, Eq, Hash, PartialEq)]
 pub enum Direction {
@@ -44,7 +57,56 @@ impl From<u8> for Direction {
     }
 }
 
+impl TryFrom<char> for Direction {
+    type Error = ParseDirectionError;
+
+    #[inline]
+    fn try_from(value: char) -> Result<Self, Self::Error> {
+        Direction::from_char(value).ok_or(ParseDirectionError)
+    }
+}
+
+impl TryFrom<Pos<i32>> for Direction {
+    type Error = DirectionDeltaError;
+
+    #[inline]
+    fn try_from(value: Pos<i32>) -> Result<Self, Self::Error> {
+        Direction::all().into_iter().find(|d| d.to_unit_pos() == value).ok_or(DirectionDeltaError)
+    }
+}
+
+impl FromStr for Direction {
+    type Err = ParseDirectionError;
+
+    /// Parses arrows (`^`, `v`, `<`, `>`), `U`, `D`, `L`, `R` and compass points (`N`, `S`, `W`, `E`, `NW`, `NE`, `SW`, `SE`).
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s {
+            "^" | "U" | "N" => Ok(Direction::Up),
+            "v" | "D" | "S" => Ok(Direction::Down),
+            "<" | "L" | "W" => Ok(Direction::Left),
+            ">" | "R" | "E" => Ok(Direction::Right),
+            "NW" => Ok(Direction::TopLeft),
+            "NE" => Ok(Direction::TopRight),
+            "SW" => Ok(Direction::BottomLeft),
+            "SE" => Ok(Direction::BottomRight),
+            _ => Err(ParseDirectionError),
+        }
+    }
+}
+
 impl Direction {
+    /// All the directions in clockwise order, starting from [`Direction::Up`].
+    const CLOCKWISE: [Direction; 8] = [
+        Direction::Up,
+        Direction::TopRight,
+        Direction::Right,
+        Direction::BottomRight,
+        Direction::Down,
+        Direction::BottomLeft,
+        Direction::Left,
+        Direction::TopLeft,
+    ];
+
     /// Returns all the directions.
     #[inline]
     pub fn all() -> [Direction; 8] {
@@ -72,6 +134,80 @@ impl Direction {
         [Direction::TopLeft, Direction::TopRight, Direction::BottomLeft, Direction::BottomRight]
     }
 
+    /// Returns the [`Direction`] of an arrow (`^`, `v`, `<`, `>`) or compass (`N`, `S`, `E`, `W`) character.
+    #[inline]
+    pub fn from_char(c: char) -> Option<Self> {
+        match c {
+            '^' | 'N' => Some(Direction::Up),
+            'v' | 'S' => Some(Direction::Down),
+            '<' | 'W' => Some(Direction::Left),
+            '>' | 'E' => Some(Direction::Right),
+            _ => None,
+        }
+    }
+
+    /// Returns the [`Direction`] from a [`Pos<T>`] to another [`Pos<T>`].
+    /// Returns [`None`] if the positions are the same, or not aligned on an axis or a 45 degree diagonal.
+    pub fn between<T: Copy + Signed>(from: &Pos<T>, to: &Pos<T>) -> Option<Self> {
+        let delta = *to - *from;
+        if !delta.x.is_zero() && !delta.y.is_zero() && delta.x.abs() != delta.y.abs() {
+            return None;
+        }
+
+        let unit = Pos { x: delta.x.signum(), y: delta.y.signum() };
+        Direction::all().into_iter().find(|&dir| Pos::from(dir) == unit)
+    }
+
+    /// Returns the unit step [`Pos<i32>`] of this [`Direction`].
+    #[inline]
+    pub fn to_unit_pos(&self) -> Pos<i32> {
+        Pos::from(*self)
+    }
+
+    /// Returns the arrow character of this [`Direction`], or `'*'` for diagonal directions.
+    #[inline]
+    pub fn to_arrow_char(&self) -> char {
+        match self {
+            Direction::Up => '^',
+            Direction::Down => 'v',
+            Direction::Left => '<',
+            Direction::Right => '>',
+            _ => '*',
+        }
+    }
+
+    /// Returns the [`Direction`] after turning clockwise by 45 degrees a number of times.
+    #[inline]
+    pub fn turn_n_clockwise(&self, steps: u32) -> Direction {
+        Self::CLOCKWISE[(self.clockwise_index() + steps as usize % 8) % 8]
+    }
+
+    /// Returns the [`Direction`] after turning counterclockwise by 45 degrees a number of times.
+    #[inline]
+    pub fn turn_n_counterclockwise(&self, steps: u32) -> Direction {
+        Self::CLOCKWISE[(self.clockwise_index() + 8 - steps as usize % 8) % 8]
+    }
+
+    /// Returns an iterator over all the directions in clockwise order, starting from this [`Direction`].
+    #[inline]
+    pub fn clockwise_iter(&self) -> impl Iterator<Item = Direction> {
+        let start = *self;
+        (0..8).map(move |steps| start.turn_n_clockwise(steps))
+    }
+
+    /// Returns an iterator over all the directions in counterclockwise order, starting from this [`Direction`].
+    #[inline]
+    pub fn counterclockwise_iter(&self) -> impl Iterator<Item = Direction> {
+        let start = *self;
+        (0..8).map(move |steps| start.turn_n_counterclockwise(steps))
+    }
+
+    /// Returns the index of this [`Direction`] in [`Direction::CLOCKWISE`].
+    #[inline]
+    fn clockwise_index(&self) -> usize {
+        Self::CLOCKWISE.iter().position(|d| d == self).unwrap_or_default()
+    }
+
     /// Returns the back [`Direction`] relative to the current [`Direction`].
     #[inline]
     pub fn back(&self) -> Direction {
@@ -174,6 +310,13 @@ impl<T> DirectionalPos<T> {
     }
 }
 
+/// Returns whether any [`DirectionalPos<T>`] appears more than once in a path.
+#[cfg(feature = "std")]
+pub fn has_loop<T: Eq + Hash + Copy>(path: &[DirectionalPos<T>]) -> bool {
+    let mut visited = HashSet::with_capacity(path.len());
+    path.iter().any(|state| !visited.insert(*state))
+}
+
 #[cfg(test)]
 mod test {
     use crate::geo::pos::PosIdx;
@@ -199,6 +342,29 @@ mod test {
         assert_eq!(Direction::Down, Direction::from(b'v'));
     }
 
+    #[test]
+    fn test_from_str() {
+        for s in ["^", "U", "N"] {
+            assert_eq!(Direction::from_str(s), Ok(Direction::Up));
+        }
+        for s in ["v", "D", "S"] {
+            assert_eq!(Direction::from_str(s), Ok(Direction::Down));
+        }
+        for s in ["<", "L", "W"] {
+            assert_eq!(Direction::from_str(s), Ok(Direction::Left));
+        }
+        for s in [">", "R", "E"] {
+            assert_eq!(Direction::from_str(s), Ok(Direction::Right));
+        }
+        assert_eq!(Direction::from_str("NW"), Ok(Direction::TopLeft));
+        assert_eq!(Direction::from_str("NE"), Ok(Direction::TopRight));
+        assert_eq!(Direction::from_str("SW"), Ok(Direction::BottomLeft));
+        assert_eq!(Direction::from_str("SE"), Ok(Direction::BottomRight));
+        assert_eq!(Direction::from_str("X"), Err(ParseDirectionError));
+        assert_eq!(Direction::from_str(""), Err(ParseDirectionError));
+        assert_eq!(Direction::from_str("up"), Err(ParseDirectionError));
+    }
+
     #[test]
     fn test_all() {
         let sut = Direction::all();
@@ -230,6 +396,154 @@ mod test {
         assert!(sut.contains(&Direction::BottomRight));
     }
 
+    #[test]
+    fn test_between() {
+        let from = Pos { x: 1, y: 1 };
+        assert_eq!(Direction::between(&from, &Pos { x: 1, y: 5 }), Some(Direction::Up));
+        assert_eq!(Direction::between(&from, &Pos { x: 1, y: -5 }), Some(Direction::Down));
+        assert_eq!(Direction::between(&from, &Pos { x: -2, y: 1 }), Some(Direction::Left));
+        assert_eq!(Direction::between(&from, &Pos { x: 2, y: 1 }), Some(Direction::Right));
+        assert_eq!(Direction::between(&from, &Pos { x: -1, y: 3 }), Some(Direction::TopLeft));
+        assert_eq!(Direction::between(&from, &Pos { x: 4, y: 4 }), Some(Direction::TopRight));
+        assert_eq!(Direction::between(&from, &Pos { x: 0, y: 0 }), Some(Direction::BottomLeft));
+        assert_eq!(Direction::between(&from, &Pos { x: 11, y: -9 }), Some(Direction::BottomRight));
+        assert_eq!(Direction::between(&from, &from), None);
+        assert_eq!(Direction::between(&from, &Pos { x: 2, y: 3 }), None);
+    }
+
+    #[test]
+    fn test_try_from_char() {
+        assert_eq!(Direction::try_from('^'), Ok(Direction::Up));
+        assert_eq!(Direction::try_from('W'), Ok(Direction::Left));
+        assert_eq!(Direction::try_from('x'), Err(ParseDirectionError));
+    }
+
+    #[test]
+    fn test_try_from_pos() {
+        assert_eq!(Direction::try_from(Pos { x: 0, y: 1 }), Ok(Direction::Up));
+        assert_eq!(Direction::try_from(Pos { x: 0, y: -1 }), Ok(Direction::Down));
+        assert_eq!(Direction::try_from(Pos { x: -1, y: 0 }), Ok(Direction::Left));
+        assert_eq!(Direction::try_from(Pos { x: 1, y: 0 }), Ok(Direction::Right));
+        assert_eq!(Direction::try_from(Pos { x: -1, y: 1 }), Ok(Direction::TopLeft));
+        assert_eq!(Direction::try_from(Pos { x: 1, y: 1 }), Ok(Direction::TopRight));
+        assert_eq!(Direction::try_from(Pos { x: -1, y: -1 }), Ok(Direction::BottomLeft));
+        assert_eq!(Direction::try_from(Pos { x: 1, y: -1 }), Ok(Direction::BottomRight));
+        assert!(Direction::all().into_iter().all(|d| Direction::try_from(d.to_unit_pos()) == Ok(d)));
+
+        assert_eq!(Direction::try_from(Pos { x: 0, y: 0 }), Err(DirectionDeltaError));
+        assert_eq!(Direction::try_from(Pos { x: 2, y: 0 }), Err(DirectionDeltaError));
+        assert_eq!(Direction::try_from(Pos { x: -1, y: 2 }), Err(DirectionDeltaError));
+    }
+
+    #[test]
+    fn test_from_char() {
+        assert_eq!(Direction::from_char('^'), Some(Direction::Up));
+        assert_eq!(Direction::from_char('v'), Some(Direction::Down));
+        assert_eq!(Direction::from_char('<'), Some(Direction::Left));
+        assert_eq!(Direction::from_char('>'), Some(Direction::Right));
+        assert_eq!(Direction::from_char('N'), Some(Direction::Up));
+        assert_eq!(Direction::from_char('S'), Some(Direction::Down));
+        assert_eq!(Direction::from_char('W'), Some(Direction::Left));
+        assert_eq!(Direction::from_char('E'), Some(Direction::Right));
+        assert_eq!(Direction::from_char('n'), None);
+        assert_eq!(Direction::from_char('.'), None);
+    }
+
+    #[test]
+    fn test_to_unit_pos() {
+        assert_eq!(Direction::Up.to_unit_pos(), Pos { x: 0, y: 1 });
+        assert_eq!(Direction::Down.to_unit_pos(), Pos { x: 0, y: -1 });
+        assert_eq!(Direction::Left.to_unit_pos(), Pos { x: -1, y: 0 });
+        assert_eq!(Direction::Right.to_unit_pos(), Pos { x: 1, y: 0 });
+        assert_eq!(Direction::TopLeft.to_unit_pos(), Pos { x: -1, y: 1 });
+        assert_eq!(Direction::TopRight.to_unit_pos(), Pos { x: 1, y: 1 });
+        assert_eq!(Direction::BottomLeft.to_unit_pos(), Pos { x: -1, y: -1 });
+        assert_eq!(Direction::BottomRight.to_unit_pos(), Pos { x: 1, y: -1 });
+
+        let p = Pos { x: 4, y: -7 };
+        assert!(Direction::all().into_iter().all(|d| p + d.to_unit_pos() == p.dest(1, d)));
+    }
+
+    #[test]
+    fn test_to_arrow_char() {
+        assert_eq!(Direction::Up.to_arrow_char(), '^');
+        assert_eq!(Direction::Down.to_arrow_char(), 'v');
+        assert_eq!(Direction::Left.to_arrow_char(), '<');
+        assert_eq!(Direction::Right.to_arrow_char(), '>');
+        assert!(Direction::diagonal().iter().all(|d| d.to_arrow_char() == '*'));
+        assert!(Direction::cross().into_iter().all(|d| Direction::from_char(d.to_arrow_char()) == Some(d)));
+    }
+
+    #[test]
+    fn test_turn_n_clockwise() {
+        assert_eq!(Direction::Up.turn_n_clockwise(0), Direction::Up);
+        assert_eq!(Direction::Up.turn_n_clockwise(1), Direction::TopRight);
+        assert_eq!(Direction::Up.turn_n_clockwise(2), Direction::Right);
+        assert_eq!(Direction::Left.turn_n_clockwise(3), Direction::TopRight);
+        assert_eq!(Direction::TopLeft.turn_n_clockwise(1), Direction::Up);
+        assert_eq!(Direction::Down.turn_n_clockwise(21), Direction::TopRight);
+        for d in Direction::all() {
+            assert_eq!(d.turn_n_clockwise(8), d);
+            assert_eq!(d.turn_n_clockwise(2), d.right());
+            assert_eq!(d.turn_n_clockwise(4), d.back());
+        }
+    }
+
+    #[test]
+    fn test_turn_n_counterclockwise() {
+        assert_eq!(Direction::Up.turn_n_counterclockwise(1), Direction::TopLeft);
+        assert_eq!(Direction::Up.turn_n_counterclockwise(2), Direction::Left);
+        assert_eq!(Direction::Right.turn_n_counterclockwise(11), Direction::TopLeft);
+        for d in Direction::all() {
+            assert_eq!(d.turn_n_counterclockwise(8), d);
+            assert_eq!(d.turn_n_counterclockwise(2), d.left());
+            for steps in 0..20 {
+                assert_eq!(d.turn_n_clockwise(steps).turn_n_counterclockwise(steps), d);
+            }
+        }
+    }
+
+    #[test]
+    fn test_clockwise_iter() {
+        let sut: Vec<_> = Direction::Up.clockwise_iter().collect();
+        assert_eq!(sut, vec![
+            Direction::Up,
+            Direction::TopRight,
+            Direction::Right,
+            Direction::BottomRight,
+            Direction::Down,
+            Direction::BottomLeft,
+            Direction::Left,
+            Direction::TopLeft,
+        ]);
+        for d in Direction::all() {
+            let sut: Vec<_> = d.clockwise_iter().collect();
+            assert_eq!(sut.len(), 8);
+            assert_eq!(sut[0], d);
+            assert!(Direction::all().iter().all(|other| sut.contains(other)));
+        }
+    }
+
+    #[test]
+    fn test_counterclockwise_iter() {
+        let sut: Vec<_> = Direction::Right.counterclockwise_iter().collect();
+        assert_eq!(sut, vec![
+            Direction::Right,
+            Direction::TopRight,
+            Direction::Up,
+            Direction::TopLeft,
+            Direction::Left,
+            Direction::BottomLeft,
+            Direction::Down,
+            Direction::BottomRight,
+        ]);
+        for d in Direction::all() {
+            let mut sut: Vec<_> = d.counterclockwise_iter().collect();
+            sut[1..].reverse();
+            assert_eq!(sut, d.clockwise_iter().collect::<Vec<_>>());
+        }
+    }
+
     #[test]
     fn test_back() {
         assert_eq!(Direction::Up.back(), Direction::Down);
@@ -318,4 +632,25 @@ mod test {
         assert_eq!(sut.pos, Pos { x: 10, y: 30 });
         assert_eq!(sut.direction, Direction::TopLeft);
     }
+
+    #[test]
+    fn test_has_loop() {
+        let path = [
+            DirectionalPos { pos: Pos { x: 0, y: 0 }, direction: Direction::Up },
+            DirectionalPos { pos: Pos { x: 0, y: 1 }, direction: Direction::Up },
+            DirectionalPos { pos: Pos { x: 0, y: 1 }, direction: Direction::Right },
+            DirectionalPos { pos: Pos { x: 1, y: 1 }, direction: Direction::Right },
+            DirectionalPos { pos: Pos { x: 1, y: 1 }, direction: Direction::Down },
+            DirectionalPos { pos: Pos { x: 1, y: 0 }, direction: Direction::Down },
+            DirectionalPos { pos: Pos { x: 1, y: 0 }, direction: Direction::Left },
+            DirectionalPos { pos: Pos { x: 0, y: 0 }, direction: Direction::Left },
+        ];
+        assert!(!has_loop(&path));
+
+        let mut looping = path.to_vec();
+        looping.push(DirectionalPos { pos: Pos { x: 0, y: 0 }, direction: Direction::Up });
+        assert!(has_loop(&looping));
+
+        assert!(!has_loop::<i32>(&[]));
+    }
 }
@@ -7,6 +7,8 @@ use alloc::vec;
 #[cfg(not(feature = "std"))]
 use alloc::vec::Vec;
 #[cfg(feature = "std")]
+use std::hash::{DefaultHasher, Hash, Hasher};
+#[cfg(feature = "std")]
 use std::string::FromUtf8Error;
 use core::fmt::Display;
 use core::ops::{Index, IndexMut};
@@ -104,6 +106,116 @@ impl<T: Clone> Grid<T> {
         }
         Ok(Self { width, height: data.len() / width, data: (*data).clone() })
     }
+
+    /// Returns a new [`Grid<T>`] surrounded by `padding` rows and columns of a value on each side.
+    pub fn extend_border(&self, padding: usize, value: T) -> Self {
+        let width = self.width + 2 * padding;
+        let mut data = vec![value.clone(); width * padding];
+        for row in self.data.chunks(self.width) {
+            data.extend(core::iter::repeat_n(value.clone(), padding));
+            data.extend_from_slice(row);
+            data.extend(core::iter::repeat_n(value.clone(), padding));
+        }
+        data.extend(core::iter::repeat_n(value, width * padding));
+        Self { width, height: self.height + 2 * padding, data }
+    }
+
+    /// Returns a new [`Grid<T>`] of the target dimensions with this [`Grid<T>`] centered in it, filling the rest with a value.
+    /// Any odd remainder of padding is placed on the right and bottom. Returns [`None`] if the target is smaller than this [`Grid<T>`].
+    pub fn pad_to(&self, target_width: usize, target_height: usize, fill: T) -> Option<Self> {
+        if target_width < self.width || target_height < self.height {
+            return None;
+        }
+
+        let (left, top) = ((target_width - self.width) / 2, (target_height - self.height) / 2);
+        let mut data = vec![fill; target_width * target_height];
+        for (y, row) in self.data.chunks(self.width).enumerate() {
+            let start = target_width * (y + top) + left;
+            data[start..start + self.width].clone_from_slice(row);
+        }
+        Some(Self { width: target_width, height: target_height, data })
+    }
+
+    /// Returns a new [`Grid<T>`] rotated by 180 degrees.
+    #[inline]
+    pub fn rotate_180(&self) -> Self {
+        Self { width: self.width, height: self.height, data: self.data.iter().rev().cloned().collect() }
+    }
+
+    /// Returns a new [`Grid<T>`] rotated clockwise by 90 degrees.
+    pub fn rotate_cw(&self) -> Self {
+        let data = (0..self.width)
+            .flat_map(|y| (0..self.height).map(move |x| self.data[self.width * (self.height - 1 - x) + y].clone()))
+            .collect();
+        Self { width: self.height, height: self.width, data }
+    }
+
+    /// Returns a new [`Grid<T>`] rotated counterclockwise by 90 degrees.
+    pub fn rotate_ccw(&self) -> Self {
+        let data = (0..self.width)
+            .flat_map(|y| (0..self.height).map(move |x| self.data[self.width * x + self.width - 1 - y].clone()))
+            .collect();
+        Self { width: self.height, height: self.width, data }
+    }
+
+    /// Returns a new [`Grid<T>`] with each row reversed.
+    #[inline]
+    pub fn flip_h(&self) -> Self {
+        let data = self.data.chunks(self.width).flat_map(|row| row.iter().rev().cloned()).collect();
+        Self { width: self.width, height: self.height, data }
+    }
+
+    /// Returns a new [`Grid<T>`] with the rows in reverse order.
+    #[inline]
+    pub fn flip_v(&self) -> Self {
+        let data = self.data.chunks(self.width).rev().flat_map(|row| row.iter().cloned()).collect();
+        Self { width: self.width, height: self.height, data }
+    }
+
+    /// Returns all 8 rotations and reflections of this [`Grid<T>`], in the order: original, clockwise, 180 degrees,
+    /// counterclockwise, horizontal flip, horizontal flip then clockwise, vertical flip, vertical flip then clockwise.
+    pub fn symmetries(&self) -> [Self; 8] {
+        let (flip_h, flip_v) = (self.flip_h(), self.flip_v());
+        let (flip_h_cw, flip_v_cw) = (flip_h.rotate_cw(), flip_v.rotate_cw());
+        [self.clone(), self.rotate_cw(), self.rotate_180(), self.rotate_ccw(), flip_h, flip_h_cw, flip_v, flip_v_cw]
+    }
+
+    /// Returns a new [`Grid<T>`] with the outer rows and columns that only contain empty cells removed.
+    /// Returns [`None`] if every cell is empty.
+    pub fn shrink_to_fit(&self, empty: impl Fn(&T) -> bool) -> Option<Self> {
+        let occupied: Vec<_> = (0..self.size()).filter(|&i| !empty(&self.data[i])).collect();
+        let min_x = occupied.iter().map(|&i| i % self.width).min()?;
+        let max_x = occupied.iter().map(|&i| i % self.width).max()?;
+        let min_y = occupied.first()? / self.width;
+        let max_y = occupied.last()? / self.width;
+
+        let width = max_x - min_x + 1;
+        let data = (min_y..=max_y)
+            .flat_map(|y| self.data[y * self.width + min_x..y * self.width + max_x + 1].iter().cloned())
+            .collect();
+        Some(Self { width, height: max_y - min_y + 1, data })
+    }
+}
+
+impl<T: Clone + PartialEq> Grid<T> {
+    /// Returns the [run-length encoding](https://en.wikipedia.org/wiki/Run-length_encoding) of a row as `(value, count)` pairs.
+    /// Returns an empty vector if the row is out of range.
+    pub fn run_length_encode_row(&self, row: usize) -> Vec<(T, usize)> {
+        let mut result: Vec<(T, usize)> = Vec::new();
+        for value in self.data.chunks(self.width).nth(row).into_iter().flatten() {
+            match result.last_mut() {
+                Some((last, count)) if last == value => *count += 1,
+                _ => result.push((value.clone(), 1)),
+            }
+        }
+        result
+    }
+
+    /// Returns whether another [`Grid<T>`] is equal to any rotation or reflection of this [`Grid<T>`].
+    #[inline]
+    pub fn equivalent_under_d4(&self, other: &Self) -> bool {
+        self.symmetries().contains(other)
+    }
 }
 
 impl<T: Copy + PartialEq> Grid<T> {
@@ -152,18 +264,109 @@ impl<T> Grid<T> {
         Area { max_x: self.width - 1, max_y: self.height - 1, min_x: 0, min_y: 0 }
     }
 
+    /// Returns an `Area<usize>` which bounds this [`Grid<T>`]. Alias of [`Grid::area`].
+    #[inline]
+    pub fn as_area(&self) -> Area<usize> {
+        self.area()
+    }
+
     /// Returns whether this [`Grid<T>`] has a [`PosIdx`].
     #[inline]
     pub fn has(&self, pos: &PosIdx) -> bool {
         pos.x < self.width && pos.y < self.height
     }
 
+    /// Returns the in range cross neighbouring [`PosIdx`]s, along with the [`Direction`] from `pos` to each of them.
+    #[inline]
+    pub fn neighbours_with_direction(&self, pos: &PosIdx) -> Vec<(PosIdx, Direction)> {
+        Direction::cross()
+            .into_iter()
+            .filter_map(|dir| pos.checked_dest(1, dir).map(|p| (p, dir)))
+            .filter(|(p, _)| self.has(p))
+            .collect()
+    }
+
+    /// Returns the diagonal neighbours of a [`PosIdx`] inside this [`Grid<T>`].
+    #[inline]
+    pub fn neighbours_diagonal(&self, pos: &PosIdx) -> Vec<PosIdx> {
+        Direction::diagonal()
+            .into_iter()
+            .filter_map(|dir| pos.checked_dest(1, dir))
+            .filter(|p| self.has(p))
+            .collect()
+    }
+
+    /// Returns an iterator from a [`PosIdx`] going in a diagonal [`Direction`] until the boundary is reached.
+    /// The iterator is empty if the [`Direction`] is not diagonal or the [`PosIdx`] is out of range.
+    #[inline]
+    pub fn diagonal_iter(&self, start: &PosIdx, direction: Direction) -> impl Iterator<Item = (PosIdx, &T)> {
+        let first = Some(*start).filter(|p| self.has(p) && Direction::diagonal().contains(&direction));
+        core::iter::successors(first, move |p| p.checked_dest(1, direction).filter(|p| self.has(p)))
+            .map(|p| (p, &self[p]))
+    }
+
+    /// Returns the product of the number of cells visible from a [`PosIdx`] in each cross direction.
+    /// Cells are visible up to and including the first cell whose value is not lower than the starting cell's value.
+    pub fn count_visible_from(&self, pos: PosIdx, value_of: impl Fn(&T) -> i32) -> usize {
+        let value = value_of(&self[pos]);
+        Direction::cross()
+            .into_iter()
+            .map(|dir| {
+                let (mut count, mut current) = (0, pos);
+                while let Some(next) = current.checked_dest(1, dir).filter(|p| self.has(p)) {
+                    count += 1;
+                    if value_of(&self[next]) >= value {
+                        break;
+                    }
+                    current = next;
+                }
+                count
+            })
+            .product()
+    }
+
+    /// Applies a function to each diagonal of this [`Grid<T>`] and collects the results. The diagonals running from top left to
+    /// bottom right are visited first, followed by the diagonals running from top right to bottom left.
+    pub fn scan_diagonal<F, R>(&self, f: F) -> Vec<R>
+    where
+        F: Fn(Vec<(PosIdx, &T)>) -> R,
+    {
+        let (width, height) = (self.width, self.height);
+        let down_right = (0..width + height - 1).map(|d| {
+            let (x, y) = if d < height { (0, height - 1 - d) } else { (d + 1 - height, 0) };
+            (0..(width - x).min(height - y)).map(|i| PosIdx { x: x + i, y: y + i }).collect::<Vec<_>>()
+        });
+        let down_left = (0..width + height - 1).map(|d| {
+            let (x, y) = (d.min(width - 1), d - d.min(width - 1));
+            (0..(x + 1).min(height - y)).map(|i| PosIdx { x: x - i, y: y + i }).collect::<Vec<_>>()
+        });
+        down_right
+            .chain(down_left)
+            .map(|diagonal| f(diagonal.into_iter().map(|p| (p, &self[p])).collect()))
+            .collect()
+    }
+
     /// Returns the size of this [`Grid<T>`].
     #[inline]
     pub fn size(&self) -> usize {
         self.width * self.height
     }
 
+    /// Applies a function to each row of this [`Grid<T>`] along with its row index, and collects the results.
+    #[inline]
+    pub fn scan_by_row(&self, f: impl Fn(usize, &[T]) -> Vec<T>) -> Vec<Vec<T>> {
+        self.data.chunks(self.width).enumerate().map(|(y, row)| f(y, row)).collect()
+    }
+
+    /// Combines this [`Grid<T>`] with another grid cell by cell, or [`None`] if their dimensions differ.
+    pub fn zip_with<U, V>(&self, other: &Grid<U>, f: impl Fn(&T, &U) -> V) -> Option<Grid<V>> {
+        if self.width != other.width || self.height != other.height {
+            return None;
+        }
+        let data = self.data.iter().zip(other.data.iter()).map(|(a, b)| f(a, b)).collect();
+        Some(Grid { width: self.width, height: self.height, data })
+    }
+
     /// Swaps 2 elements in this [`Grid<T>`].
     #[inline]
     pub fn swap(&mut self, pos: &PosIdx, other: &PosIdx) {
@@ -171,6 +374,18 @@ impl<T> Grid<T> {
     }
 }
 
+#[cfg(feature = "std")]
+impl<T: Hash> Grid<T> {
+    /// Returns a hash of the dimensions and content of this [`Grid<T>`], computed using [`DefaultHasher`].
+    /// The hash is stable within a single program run, so it can be used for cycle detection.
+    #[inline]
+    pub fn hash_state(&self) -> u64 {
+        let mut hasher = DefaultHasher::new();
+        self.hash(&mut hasher);
+        hasher.finish()
+    }
+}
+
 impl CharGrid {
     /// Extracts a list of [`PosIdx`] into a `String`. Out of range [`PosIdx`]s are ignored.
     #[inline]
@@ -248,6 +463,140 @@ mod test {
         assert!(sut.is_err());
     }
 
+    #[test]
+    fn test_extend_border() {
+        let grid = Grid { height: 2, width: 3, data: vec![1, 2, 3, 4, 5, 6] };
+        let sut = grid.extend_border(1, 0);
+        assert_eq!(sut, Grid { height: 4, width: 5, data: vec![0, 0, 0, 0, 0, 0, 1, 2, 3, 0, 0, 4, 5, 6, 0, 0, 0, 0, 0, 0] });
+
+        let sut = grid.extend_border(3, 9);
+        assert_eq!(sut.width, 9);
+        assert_eq!(sut.height, 8);
+        assert_eq!(sut.data.len(), 72);
+        for pos in grid.area() {
+            assert_eq!(sut[Pos { x: pos.x + 3, y: pos.y + 3 }], grid[pos]);
+        }
+        assert_eq!(sut.data.iter().filter(|&&v| v == 9).count(), 66);
+
+        assert_eq!(grid.extend_border(0, 0), grid);
+    }
+
+    #[test]
+    fn test_pad_to() {
+        let grid = Grid { height: 2, width: 2, data: vec![1, 2, 3, 4] };
+        let sut = grid.pad_to(4, 4, 0).unwrap();
+        assert_eq!(sut, Grid { height: 4, width: 4, data: vec![0, 0, 0, 0, 0, 1, 2, 0, 0, 3, 4, 0, 0, 0, 0, 0] });
+        assert_eq!(sut, grid.extend_border(1, 0));
+
+        let sut = grid.pad_to(5, 3, 9).unwrap();
+        assert_eq!(sut.width, 5);
+        assert_eq!(sut.height, 3);
+        assert_eq!(sut.data, vec![9, 1, 2, 9, 9, 9, 3, 4, 9, 9, 9, 9, 9, 9, 9]);
+
+        assert_eq!(grid.pad_to(2, 2, 0), Some(grid.clone()));
+        assert_eq!(grid.pad_to(1, 4, 0), None);
+        assert_eq!(grid.pad_to(4, 1, 0), None);
+    }
+
+    #[test]
+    fn test_rotate_180() {
+        let sut = Grid { height: 2, width: 3, data: vec![1, 2, 3, 4, 5, 6] };
+        assert_eq!(sut.rotate_180(), Grid { height: 2, width: 3, data: vec![6, 5, 4, 3, 2, 1] });
+        assert_eq!(sut.rotate_180().rotate_180(), sut);
+
+        let sut = Grid { height: 4, width: 1, data: vec![1, 2, 3, 4] };
+        assert_eq!(sut.rotate_180(), Grid { height: 4, width: 1, data: vec![4, 3, 2, 1] });
+        assert_eq!(sut.rotate_180().rotate_180(), sut);
+    }
+
+    #[test]
+    fn test_rotate_cw() {
+        let sut = Grid { height: 2, width: 3, data: vec![1, 2, 3, 4, 5, 6] };
+        assert_eq!(sut.rotate_cw(), Grid { height: 3, width: 2, data: vec![4, 1, 5, 2, 6, 3] });
+        assert_eq!(sut.rotate_cw().rotate_cw(), sut.rotate_180());
+        assert_eq!(sut.rotate_cw().rotate_cw().rotate_cw().rotate_cw(), sut);
+    }
+
+    #[test]
+    fn test_rotate_ccw() {
+        let sut = Grid { height: 2, width: 3, data: vec![1, 2, 3, 4, 5, 6] };
+        assert_eq!(sut.rotate_ccw(), Grid { height: 3, width: 2, data: vec![3, 6, 2, 5, 1, 4] });
+        assert_eq!(sut.rotate_ccw().rotate_cw(), sut);
+        assert_eq!(sut.rotate_ccw().rotate_ccw(), sut.rotate_180());
+    }
+
+    #[test]
+    fn test_flip_h() {
+        let sut = Grid { height: 2, width: 3, data: vec![1, 2, 3, 4, 5, 6] };
+        assert_eq!(sut.flip_h(), Grid { height: 2, width: 3, data: vec![3, 2, 1, 6, 5, 4] });
+        assert_eq!(sut.flip_h().flip_h(), sut);
+    }
+
+    #[test]
+    fn test_flip_v() {
+        let sut = Grid { height: 2, width: 3, data: vec![1, 2, 3, 4, 5, 6] };
+        assert_eq!(sut.flip_v(), Grid { height: 2, width: 3, data: vec![4, 5, 6, 1, 2, 3] });
+        assert_eq!(sut.flip_v().flip_v(), sut);
+        assert_eq!(sut.flip_v().flip_h(), sut.rotate_180());
+    }
+
+    #[test]
+    fn test_symmetries() {
+        let grid = Grid { height: 2, width: 3, data: vec![1, 2, 3, 4, 5, 6] };
+        let sut = grid.symmetries();
+        assert_eq!(sut[0], grid);
+        assert_eq!(sut[1], grid.rotate_cw());
+        assert_eq!(sut[4], grid.flip_h());
+        assert_eq!(sut[7], grid.flip_v().rotate_cw());
+        for (i, a) in sut.iter().enumerate() {
+            assert!(sut[i + 1..].iter().all(|b| a != b));
+        }
+
+        let grid = Grid { height: 2, width: 2, data: vec![1, 1, 2, 2] };
+        let sut = grid.symmetries();
+        let distinct = sut.iter().enumerate().filter(|&(i, a)| !sut[..i].contains(a)).count();
+        assert_eq!(distinct, 4);
+
+        let grid = Grid { height: 1, width: 1, data: vec![7] };
+        assert!(grid.symmetries().iter().all(|g| *g == grid));
+    }
+
+    #[test]
+    fn test_shrink_to_fit() {
+        let sut = Grid { height: 4, width: 5, data: vec![0, 0, 0, 0, 0, 0, 1, 0, 0, 0, 0, 0, 2, 0, 0, 0, 0, 0, 0, 0] };
+        assert_eq!(sut.shrink_to_fit(|&v| v == 0), Some(Grid { height: 2, width: 2, data: vec![1, 0, 0, 2] }));
+
+        let sut = Grid { height: 3, width: 3, data: vec![0, 0, 0, 5, 0, 5, 0, 0, 0] };
+        assert_eq!(sut.shrink_to_fit(|&v| v == 0), Some(Grid { height: 1, width: 3, data: vec![5, 0, 5] }));
+
+        let sut = Grid { height: 3, width: 3, data: vec![1, 2, 3, 4, 5, 6, 7, 8, 9] };
+        assert_eq!(sut.shrink_to_fit(|&v| v == 0), Some(sut.clone()));
+
+        let sut = Grid { height: 2, width: 2, data: vec![0, 0, 0, 0] };
+        assert_eq!(sut.shrink_to_fit(|&v| v == 0), None);
+    }
+
+    #[test]
+    fn test_run_length_encode_row() {
+        let sut: CharGrid = "aabbbc\nxxxxxx\nxyxyxy".parse().unwrap();
+        assert_eq!(sut.run_length_encode_row(0), vec![(b'a', 2), (b'b', 3), (b'c', 1)]);
+        assert_eq!(sut.run_length_encode_row(1), vec![(b'x', 6)]);
+        assert_eq!(sut.run_length_encode_row(2), vec![(b'x', 1), (b'y', 1), (b'x', 1), (b'y', 1), (b'x', 1), (b'y', 1)]);
+        assert!(sut.run_length_encode_row(3).is_empty());
+    }
+
+    #[test]
+    fn test_equivalent_under_d4() {
+        let grid = Grid { height: 2, width: 3, data: vec![1, 2, 3, 4, 5, 6] };
+        assert!(grid.equivalent_under_d4(&grid));
+        assert!(grid.equivalent_under_d4(&grid.rotate_cw()));
+        assert!(grid.equivalent_under_d4(&grid.rotate_ccw().flip_v()));
+        assert!(grid.equivalent_under_d4(&grid.flip_h()));
+        assert!(grid.rotate_180().equivalent_under_d4(&grid));
+        assert!(!grid.equivalent_under_d4(&Grid { height: 2, width: 3, data: vec![1, 2, 3, 4, 6, 5] }));
+        assert!(!grid.equivalent_under_d4(&Grid { height: 1, width: 6, data: vec![1, 2, 3, 4, 5, 6] }));
+    }
+
     #[test]
     fn test_find() {
         let sut = Grid { height: 3, width: 3, data: vec![1, 2, 3, 4, 5, 6, 7, 8, 9] };
@@ -297,6 +646,19 @@ mod test {
 
         let sut = Grid { height: 1, width: 1, data: vec![0] };
         assert_eq!(sut.area(), Area { max_x: 0, max_y: 0, min_x: 0, min_y: 0 });
+
+        let sut = Grid { height: 3, width: 7, data: vec![0; 21] };
+        assert_eq!(sut.area().rows(), sut.height);
+        assert_eq!(sut.area().cols(), sut.width);
+        assert_eq!(sut.area().size(), sut.size());
+    }
+
+    #[test]
+    fn test_as_area() {
+        let sut = Grid { height: 3, width: 7, data: vec![0; 21] };
+        assert_eq!(sut.as_area(), sut.area());
+        assert_eq!(sut.as_area().rows(), sut.height);
+        assert_eq!(sut.as_area().cols(), sut.width);
     }
 
     #[test]
@@ -309,12 +671,147 @@ mod test {
         assert!(!sut.has(&Pos { x: 9, y: 10 }));
     }
 
+    #[test]
+    fn test_neighbours_with_direction() {
+        let sut = Grid { height: 3, width: 3, data: vec![1, 2, 3, 4, 5, 6, 7, 8, 9] };
+        let neighbours = sut.neighbours_with_direction(&Pos { x: 1, y: 1 });
+        assert_eq!(neighbours.len(), 4);
+        for (pos, dir) in neighbours {
+            let delta = Pos { x: pos.x as i32 - 1, y: pos.y as i32 - 1 };
+            assert_eq!(delta, Pos::from(dir));
+        }
+
+        let neighbours = sut.neighbours_with_direction(&Pos { x: 0, y: 0 });
+        assert_eq!(neighbours.len(), 2);
+        assert!(neighbours.contains(&(Pos { x: 0, y: 1 }, Direction::Up)));
+        assert!(neighbours.contains(&(Pos { x: 1, y: 0 }, Direction::Right)));
+
+        let neighbours = sut.neighbours_with_direction(&Pos { x: 2, y: 1 });
+        assert_eq!(neighbours.len(), 3);
+        assert!(neighbours.contains(&(Pos { x: 2, y: 2 }, Direction::Up)));
+        assert!(neighbours.contains(&(Pos { x: 2, y: 0 }, Direction::Down)));
+        assert!(neighbours.contains(&(Pos { x: 1, y: 1 }, Direction::Left)));
+    }
+
+    #[test]
+    fn test_neighbours_diagonal() {
+        let sut = Grid { height: 3, width: 4, data: vec![0; 12] };
+        let neighbours = sut.neighbours_diagonal(&Pos { x: 1, y: 1 });
+        assert_eq!(neighbours.len(), 4);
+        assert!(neighbours.iter().all(|p| p.x.abs_diff(1) == 1 && p.y.abs_diff(1) == 1));
+
+        assert_eq!(sut.neighbours_diagonal(&Pos { x: 0, y: 0 }), vec![Pos { x: 1, y: 1 }]);
+        assert_eq!(sut.neighbours_diagonal(&Pos { x: 3, y: 2 }), vec![Pos { x: 2, y: 1 }]);
+
+        let neighbours = sut.neighbours_diagonal(&Pos { x: 2, y: 0 });
+        assert_eq!(neighbours.len(), 2);
+        assert!(neighbours.contains(&Pos { x: 1, y: 1 }));
+        assert!(neighbours.contains(&Pos { x: 3, y: 1 }));
+
+        let sut = Grid { height: 1, width: 1, data: vec![0] };
+        assert!(sut.neighbours_diagonal(&Pos { x: 0, y: 0 }).is_empty());
+    }
+
+    #[test]
+    fn test_diagonal_iter() {
+        let sut = Grid { height: 3, width: 4, data: vec![1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12] };
+        let cells: Vec<_> = sut.diagonal_iter(&Pos { x: 0, y: 0 }, Direction::TopRight).collect();
+        assert_eq!(cells, vec![(Pos { x: 0, y: 0 }, &1), (Pos { x: 1, y: 1 }, &6), (Pos { x: 2, y: 2 }, &11)]);
+
+        let cells: Vec<_> = sut.diagonal_iter(&Pos { x: 3, y: 2 }, Direction::BottomLeft).collect();
+        assert_eq!(cells, vec![(Pos { x: 3, y: 2 }, &12), (Pos { x: 2, y: 1 }, &7), (Pos { x: 1, y: 0 }, &2)]);
+
+        let cells: Vec<_> = sut.diagonal_iter(&Pos { x: 1, y: 0 }, Direction::TopLeft).collect();
+        assert_eq!(cells, vec![(Pos { x: 1, y: 0 }, &2), (Pos { x: 0, y: 1 }, &5)]);
+
+        let cells: Vec<_> = sut.diagonal_iter(&Pos { x: 3, y: 0 }, Direction::BottomRight).collect();
+        assert_eq!(cells, vec![(Pos { x: 3, y: 0 }, &4)]);
+
+        assert_eq!(sut.diagonal_iter(&Pos { x: 0, y: 0 }, Direction::Up).count(), 0);
+        assert_eq!(sut.diagonal_iter(&Pos { x: 4, y: 0 }, Direction::TopRight).count(), 0);
+    }
+
+    #[test]
+    fn test_scan_diagonal() {
+        let sut = Grid { height: 2, width: 3, data: vec![1, 2, 3, 4, 5, 6] };
+        let diagonals = sut.scan_diagonal(|cells| cells.into_iter().map(|(_, &v)| v).collect::<Vec<_>>());
+        assert_eq!(diagonals, vec![
+            vec![4],
+            vec![1, 5],
+            vec![2, 6],
+            vec![3],
+            vec![1],
+            vec![2, 4],
+            vec![3, 5],
+            vec![6],
+        ]);
+
+        let sut = Grid::new(5, 3, 0).unwrap();
+        let lengths = sut.scan_diagonal(|cells| cells.len());
+        assert_eq!(lengths.len(), 14);
+        assert_eq!(lengths[..7].iter().sum::<usize>(), sut.size());
+        assert_eq!(lengths[7..].iter().sum::<usize>(), sut.size());
+
+        let positions = sut.scan_diagonal(|cells| cells.into_iter().map(|(p, _)| p).collect::<Vec<_>>());
+        assert_eq!(positions[2], vec![PosIdx { x: 0, y: 0 }, PosIdx { x: 1, y: 1 }, PosIdx { x: 2, y: 2 }]);
+        assert_eq!(positions[11], vec![PosIdx { x: 4, y: 0 }, PosIdx { x: 3, y: 1 }, PosIdx { x: 2, y: 2 }]);
+
+        let sut = Grid { height: 1, width: 1, data: vec![9] };
+        assert_eq!(sut.scan_diagonal(|cells| cells.len()), vec![1, 1]);
+    }
+
     #[test]
     fn test_size() {
         let sut = Grid { height: 10, width: 10, data: vec![0; 100] };
         assert_eq!(sut.size(), 100);
     }
 
+    #[test]
+    fn test_scan_by_row() {
+        let grid = Grid { height: 3, width: 2, data: vec![1, 2, 3, 4, 5, 6] };
+        let sut = grid.scan_by_row(|y, row| vec![y, row.len()]);
+        assert_eq!(sut, vec![vec![0, 2], vec![1, 2], vec![2, 2]]);
+
+        let sut = grid.scan_by_row(|_, row| row.iter().rev().copied().collect());
+        for (y, row) in sut.iter().enumerate() {
+            assert_eq!(row[0], grid[Pos { x: 1, y }]);
+            assert_eq!(row[1], grid[Pos { x: 0, y }]);
+        }
+
+        let sut = grid.scan_by_row(|_, row| row.iter().copied().filter(|v| v % 2 == 0).collect());
+        assert_eq!(sut, vec![vec![2], vec![4], vec![6]]);
+    }
+
+    #[test]
+    fn test_zip_with() {
+        let grid = Grid { height: 2, width: 3, data: vec![1, 2, 3, 4, 5, 6] };
+        let other = Grid { height: 2, width: 3, data: vec![true, false, true, false, true, false] };
+        let sut = grid.zip_with(&other, |&a, &b| if b { a * 10 } else { a }).unwrap();
+        assert_eq!(sut, Grid { height: 2, width: 3, data: vec![10, 2, 30, 4, 50, 6] });
+
+        let other = Grid { height: 2, width: 3, data: vec![b'a', b'b', b'c', b'd', b'e', b'f'] };
+        let sut = grid.zip_with(&other, |&a, &b| (a, b)).unwrap();
+        assert_eq!(sut.width, 3);
+        assert_eq!(sut.height, 2);
+        assert_eq!(sut[Pos { x: 2, y: 1 }], (6, b'f'));
+
+        let other = Grid { height: 3, width: 2, data: vec![1, 2, 3, 4, 5, 6] };
+        assert!(grid.zip_with(&other, |&a, &b| a + b).is_none());
+    }
+
+    #[test]
+    fn test_count_visible_from() {
+        let sut: CharGrid = "30373\n25512\n65332\n33549\n35390".parse().unwrap();
+        let value_of = |&c: &u8| (c - b'0') as i32;
+        assert_eq!(sut.count_visible_from(PosIdx { x: 2, y: 1 }, value_of), 4);
+        assert_eq!(sut.count_visible_from(PosIdx { x: 2, y: 3 }, value_of), 8);
+        assert_eq!(sut.count_visible_from(PosIdx { x: 0, y: 2 }, value_of), 0);
+        assert_eq!(sut.area().into_iter().map(|p| sut.count_visible_from(p, value_of)).max(), Some(8));
+
+        let sut = Grid { height: 1, width: 1, data: vec![5] };
+        assert_eq!(sut.count_visible_from(PosIdx { x: 0, y: 0 }, |&v| v), 0);
+    }
+
     #[test]
     fn test_swap() {
         let mut sut = Grid { height: 3, width: 3, data: vec![1, 2, 3, 4, 5, 6, 7, 8, 9] };
@@ -325,6 +822,21 @@ mod test {
         assert_eq!(sut.data, vec![2, 1, 3, 4, 9, 6, 7, 8, 5]);
     }
 
+    #[test]
+    fn test_hash_state() {
+        let grid = Grid { height: 2, width: 3, data: vec![1, 2, 3, 4, 5, 6] };
+        assert_eq!(grid.hash_state(), grid.clone().hash_state());
+        assert_ne!(grid.hash_state(), Grid { height: 3, width: 2, data: vec![1, 2, 3, 4, 5, 6] }.hash_state());
+        assert_ne!(grid.hash_state(), grid.rotate_180().hash_state());
+
+        let mut sut = grid.clone();
+        sut[PosIdx { x: 1, y: 1 }] = 0;
+        assert_ne!(sut.hash_state(), grid.hash_state());
+
+        sut[PosIdx { x: 1, y: 1 }] = 5;
+        assert_eq!(sut.hash_state(), grid.hash_state());
+    }
+
     #[test]
     pub fn test_extract_string() {
         let sut = Grid { height: 3, width: 3, data: vec![b'a', b'b', b'c', b'd', b'e', b'f', b'g', b'h', b'i'] };
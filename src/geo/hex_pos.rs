@@ -0,0 +1,138 @@
+use core::fmt::{Display, Formatter};
+use core::str::FromStr;
+
+/// An error returned when parsing a [`HexPos`] fails.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct ParseHexPosError;
+
+/// A position in a hexagonal grid, using [cube coordinates](https://www.redblobgames.com/grids/hexagons/#coordinates-cube).
+/// The coordinates always satisfy `q + r + s == 0`.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct HexPos {
+    pub q: i32,
+    pub r: i32,
+    pub s: i32,
+}
+
+impl Display for HexPos {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "({}, {}, {})", self.q, self.r, self.s)
+    }
+}
+
+impl FromStr for HexPos {
+    type Err = ParseHexPosError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let inner = s.trim().strip_prefix('(').and_then(|s| s.strip_suffix(')')).ok_or(ParseHexPosError)?;
+        let mut coords = inner.split(',').map(|c| c.trim().parse::<i32>());
+        let (Some(Ok(q)), Some(Ok(r)), Some(Ok(s)), None) = (coords.next(), coords.next(), coords.next(), coords.next()) else {
+            return Err(ParseHexPosError);
+        };
+
+        if q.checked_add(r).and_then(|v| v.checked_add(s)) != Some(0) {
+            return Err(ParseHexPosError);
+        }
+        Ok(Self { q, r, s })
+    }
+}
+
+impl HexPos {
+    /// The 6 unit offsets to neighbouring [`HexPos`]s, in counterclockwise order.
+    const DIRECTIONS: [(i32, i32); 6] = [(1, 0), (1, -1), (0, -1), (-1, 0), (-1, 1), (0, 1)];
+
+    /// Returns a new [`HexPos`], deriving `s` from `q` and `r`.
+    #[inline]
+    pub fn new(q: i32, r: i32) -> Self {
+        Self { q, r, s: -q - r }
+    }
+
+    /// Returns the number of steps between two [`HexPos`]s.
+    #[inline]
+    pub fn distance(&self, other: &Self) -> i32 {
+        (self.q - other.q).abs().max((self.r - other.r).abs()).max((self.s - other.s).abs())
+    }
+
+    /// Returns an iterator over this [`HexPos`] followed by every ring around it, from radius 1 up to `max_radius`.
+    /// Radii above [`i32::MAX`] are clamped, since they cannot be represented by the coordinates.
+    pub fn spiral_iter(&self, max_radius: u32) -> impl Iterator<Item = HexPos> {
+        let center = *self;
+        core::iter::once(center).chain((1..=i32::try_from(max_radius).unwrap_or(i32::MAX)).flat_map(move |radius| {
+            (0..6).flat_map(move |side| {
+                let (corner_q, corner_r) = Self::DIRECTIONS[(side + 4) % 6];
+                let (dq, dr) = Self::DIRECTIONS[side];
+                (0..radius).map(move |step| {
+                    Self::new(center.q + corner_q * radius + dq * step, center.r + corner_r * radius + dr * step)
+                })
+            })
+        }))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+    use std::format;
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let sut = HexPos { q: 1, r: -3, s: 2 };
+        assert_eq!(format!("{}", sut), "(1, -3, 2)");
+    }
+
+    #[test]
+    fn test_from_str() {
+        assert_eq!(HexPos::from_str("(1, -3, 2)"), Ok(HexPos { q: 1, r: -3, s: 2 }));
+        assert_eq!(HexPos::from_str(" (0,0,0) "), Ok(HexPos { q: 0, r: 0, s: 0 }));
+        assert_eq!(HexPos::from_str("(1, 1, 1)"), Err(ParseHexPosError));
+        assert_eq!(HexPos::from_str("1, -1, 0"), Err(ParseHexPosError));
+        assert_eq!(HexPos::from_str("(1, -1)"), Err(ParseHexPosError));
+        assert_eq!(HexPos::from_str("(1, -1, 0, 0)"), Err(ParseHexPosError));
+        assert_eq!(HexPos::from_str("(a, b, c)"), Err(ParseHexPosError));
+        assert_eq!(HexPos::from_str("(2147483647, 1, -1)"), Err(ParseHexPosError));
+        assert_eq!(HexPos::from_str("(-2147483648, -1, 1)"), Err(ParseHexPosError));
+        assert_eq!(HexPos::from_str("(2147483647, -2147483648, 1)"), Ok(HexPos { q: 2147483647, r: -2147483648, s: 1 }));
+
+        let pos = HexPos::new(4, -7);
+        assert_eq!(format!("{}", pos).parse(), Ok(pos));
+    }
+
+    #[test]
+    fn test_new() {
+        let sut = HexPos::new(1, -3);
+        assert_eq!(sut, HexPos { q: 1, r: -3, s: 2 });
+    }
+
+    #[test]
+    fn test_distance() {
+        let sut = HexPos::new(1, -3);
+        assert_eq!(sut.distance(&sut), 0);
+        assert_eq!(sut.distance(&HexPos::new(2, -3)), 1);
+        assert_eq!(sut.distance(&HexPos::new(0, 0)), 3);
+        assert_eq!(HexPos::new(-2, 4).distance(&HexPos::new(3, -1)), 5);
+    }
+
+    #[test]
+    fn test_spiral_iter() {
+        let center = HexPos::new(2, -1);
+        let sut: Vec<_> = center.spiral_iter(0).collect();
+        assert_eq!(sut, vec![center]);
+
+        for radius in 1..5 {
+            let sut: Vec<_> = center.spiral_iter(radius).collect();
+            let r = radius as usize;
+            assert_eq!(sut.len(), 3 * r * (r + 1) + 1);
+            assert_eq!(sut.iter().collect::<HashSet<_>>().len(), sut.len());
+            assert!(sut.iter().all(|p| p.distance(&center) <= radius as i32 && p.q + p.r + p.s == 0));
+            assert!(sut.windows(2).all(|w| w[0].distance(&center) <= w[1].distance(&center)));
+        }
+
+        let sut: Vec<_> = center.spiral_iter(1).skip(1).collect();
+        assert!(sut.windows(2).all(|w| w[0].distance(&w[1]) == 1));
+        assert_eq!(sut[0].distance(&sut[5]), 1);
+
+        assert!(center.spiral_iter(u32::MAX).take(19).eq(center.spiral_iter(2)));
+    }
+}
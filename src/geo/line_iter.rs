@@ -0,0 +1,115 @@
+use num::Num;
+use crate::geo::direction::Direction;
+use crate::geo::pos::Pos;
+
+/// Represents an iterator that walks a straight line of [`Pos<T>`]s in a [`Direction`].
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct LineIterator<T> {
+    pub(crate) front: Pos<T>,
+    pub(crate) back: Option<Pos<T>>,
+    pub(crate) direction: Direction,
+    pub(crate) remaining: usize,
+    pub(crate) step: T,
+}
+
+impl<T> LineIterator<T> {
+    /// Returns a new [`LineIterator<T>`] yielding `distance` [`Pos<T>`]s `step` apart, starting from `start`.
+    pub(crate) fn new(start: Pos<T>, distance: usize, direction: Direction, step: T) -> Self {
+        Self { front: start, back: None, direction, remaining: distance, step }
+    }
+}
+
+impl<T: Copy + Num> Iterator for LineIterator<T> {
+    type Item = Pos<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let result = self.front;
+        self.remaining -= 1;
+        if self.remaining > 0 {
+            self.front = self.front.dest(self.step, self.direction);
+        }
+        Some(result)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+}
+
+impl<T: Copy + Num> DoubleEndedIterator for LineIterator<T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.remaining == 0 {
+            return None;
+        }
+
+        let result = match self.back {
+            Some(back) => back,
+            None => (1..self.remaining).fold(self.front, |pos, _| pos.dest(self.step, self.direction)),
+        };
+        self.remaining -= 1;
+        if self.remaining > 0 {
+            self.back = Some(result.dest(self.step, self.direction.back()));
+        }
+        Some(result)
+    }
+}
+
+impl<T: Copy + Num> ExactSizeIterator for LineIterator<T> {}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_iter() {
+        let iter = LineIterator { front: Pos { x: 0, y: 0 }, back: None, direction: Direction::Right, remaining: 4, step: 1 };
+        let sut: Vec<_> = iter.collect();
+        assert_eq!(sut, vec![Pos { x: 0, y: 0 }, Pos { x: 1, y: 0 }, Pos { x: 2, y: 0 }, Pos { x: 3, y: 0 }]);
+
+        let iter = LineIterator { front: Pos { x: 5, y: 5 }, back: None, direction: Direction::Up, remaining: 1, step: 1 };
+        let sut: Vec<_> = iter.collect();
+        assert_eq!(sut, vec![Pos { x: 5, y: 5 }]);
+
+        let iter = LineIterator { front: Pos { x: 5, y: 5 }, back: None, direction: Direction::Up, remaining: 0, step: 1 };
+        assert_eq!(iter.count(), 0);
+    }
+
+    #[test]
+    fn test_next_back() {
+        let iter = LineIterator { front: Pos { x: 0, y: 0 }, back: None, direction: Direction::TopLeft, remaining: 3, step: 1 };
+        let sut: Vec<_> = iter.clone().rev().collect();
+        assert_eq!(sut, vec![Pos { x: -2, y: 2 }, Pos { x: -1, y: 1 }, Pos { x: 0, y: 0 }]);
+        let mut forward: Vec<_> = iter.collect();
+        forward.reverse();
+        assert_eq!(sut, forward);
+
+        let mut sut: LineIterator<usize> = LineIterator { front: Pos { x: 0, y: 4 }, back: None, direction: Direction::Down, remaining: 5, step: 1 };
+        assert_eq!(sut.next(), Some(Pos { x: 0, y: 4 }));
+        assert_eq!(sut.next_back(), Some(Pos { x: 0, y: 0 }));
+        assert_eq!(sut.next_back(), Some(Pos { x: 0, y: 1 }));
+        assert_eq!(sut.next(), Some(Pos { x: 0, y: 3 }));
+        assert_eq!(sut.next_back(), Some(Pos { x: 0, y: 2 }));
+        assert_eq!(sut.next(), None);
+        assert_eq!(sut.next_back(), None);
+
+        let mut sut = LineIterator { front: Pos { x: 0, y: 0 }, back: None, direction: Direction::Right, remaining: 4, step: 1 };
+        assert_eq!(sut.len(), 4);
+        assert_eq!(sut.next(), Some(Pos { x: 0, y: 0 }));
+        assert_eq!(sut.len(), 3);
+        assert_eq!(sut.next_back(), Some(Pos { x: 3, y: 0 }));
+        assert_eq!(sut.len(), 2);
+        assert_eq!(sut.collect::<Vec<_>>(), vec![Pos { x: 1, y: 0 }, Pos { x: 2, y: 0 }]);
+    }
+
+    #[test]
+    fn test_iter_step() {
+        let iter = LineIterator { front: Pos { x: 0, y: 0 }, back: None, direction: Direction::TopRight, remaining: 4, step: 2 };
+        let sut: Vec<_> = iter.clone().collect();
+        assert_eq!(sut, vec![Pos { x: 0, y: 0 }, Pos { x: 2, y: 2 }, Pos { x: 4, y: 4 }, Pos { x: 6, y: 6 }]);
+        assert!(iter.rev().eq(sut.into_iter().rev()));
+    }
+}
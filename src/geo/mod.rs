@@ -1,5 +1,12 @@
 pub mod area;
 pub mod area_iter;
+pub mod area3;
+pub mod area3_iter;
 pub mod direction;
 pub mod grid;
+pub mod hex_pos;
+pub mod line_iter;
 pub mod pos;
+pub mod pos3;
+pub mod ring_iter;
+pub mod sparse_grid;
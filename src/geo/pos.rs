@@ -1,11 +1,17 @@
 use core::fmt::{Display, Formatter};
+use core::iter::{successors, Sum};
 use core::ops::{Add, AddAssign, Div, DivAssign, Mul, MulAssign, Neg, Rem, RemAssign, Sub, SubAssign};
-use num::{CheckedAdd, CheckedSub, Num, Signed};
-use num::traits::{CheckedNeg, CheckedRem};
+use num::{CheckedAdd, CheckedSub, Integer, Num, Signed};
+use num::traits::{CheckedNeg, CheckedRem, Euclid};
+#[cfg(feature = "std")]
+use num::Float;
+use crate::geo::area::Area;
 use crate::geo::direction::Direction;
+use crate::geo::line_iter::LineIterator;
+use crate::geo::ring_iter::RingIterator;
 
 /// A position in a 2D space.
-#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+#[derive(Clone, Copy, Debug, Default, Eq, Hash, Ord, PartialEq, PartialOrd)]
 pub struct Pos<T> {
     pub x: T,
     pub y: T,
@@ -42,6 +48,20 @@ impl<T: Copy + CheckedAdd> CheckedAdd for Pos<T> {
     }
 }
 
+impl<T: Add<Output = T> + Default> Sum for Pos<T> {
+    #[inline]
+    fn sum<I: Iterator<Item = Self>>(iter: I) -> Self {
+        iter.fold(Self::default(), |acc, p| acc + p)
+    }
+}
+
+impl<'a, T: Copy + Add<Output = T> + Default + 'a> Sum<&'a Pos<T>> for Pos<T> {
+    #[inline]
+    fn sum<I: Iterator<Item = &'a Pos<T>>>(iter: I) -> Self {
+        iter.copied().sum()
+    }
+}
+
 impl<T: Sub<Output = T>> Sub for Pos<T> {
     type Output = Self;
 
@@ -117,6 +137,23 @@ impl<T: RemAssign> RemAssign for Pos<T> {
     }
 }
 
+impl<T: Copy + Rem<Output = T>> Rem<T> for Pos<T> {
+    type Output = Self;
+
+    #[inline]
+    fn rem(self, rhs: T) -> Self::Output {
+        Self { x: self.x % rhs, y: self.y % rhs }
+    }
+}
+
+impl<T: Copy + RemAssign> RemAssign<T> for Pos<T> {
+    #[inline]
+    fn rem_assign(&mut self, rhs: T) {
+        self.x %= rhs;
+        self.y %= rhs;
+    }
+}
+
 impl<T: CheckedRem> CheckedRem for Pos<T> {
     #[inline]
     fn checked_rem(&self, v: &Self) -> Option<Self> {
@@ -156,6 +193,21 @@ impl<T: Neg<Output = T> + Num> From<Direction> for Pos<T> {
     }
 }
 
+impl<T> From<[T; 2]> for Pos<T> {
+    #[inline]
+    fn from(value: [T; 2]) -> Self {
+        let [x, y] = value;
+        Self { x, y }
+    }
+}
+
+impl<T> From<Pos<T>> for [T; 2] {
+    #[inline]
+    fn from(value: Pos<T>) -> Self {
+        [value.x, value.y]
+    }
+}
+
 impl<T> Pos<T> {
     /// Returns a new [`Pos<T>`].
     #[inline]
@@ -186,12 +238,88 @@ impl<T: Copy + Num> Pos<T> {
         }
     }
 
+    /// Returns an iterator over all [`Pos<T>`]s at an exact [Manhattan distance](https://en.wikipedia.org/wiki/Taxicab_geometry),
+    /// going anticlockwise from the rightmost [`Pos<T>`]. A radius of zero yields only this [`Pos<T>`].
+    #[inline]
+    pub fn manhattan_ring_iter(&self, radius: T) -> RingIterator<T> {
+        RingIterator {
+            current: self.dest(radius, Direction::Right),
+            directions: [Direction::TopLeft, Direction::BottomLeft, Direction::BottomRight, Direction::TopRight],
+            side_length: radius,
+            side: 0,
+            step: T::zero(),
+        }
+    }
+
+    /// Returns an iterator over the taxicab circle (diamond ring) of a radius. Alias of [`Pos::manhattan_ring_iter`].
+    #[inline]
+    pub fn taxicab_circle_iter(&self, radius: T) -> RingIterator<T> {
+        self.manhattan_ring_iter(radius)
+    }
+
+    /// Returns an iterator over all [`Pos<T>`]s at an exact [Chebyshev distance](https://en.wikipedia.org/wiki/Chebyshev_distance),
+    /// going clockwise from the top left [`Pos<T>`]. A distance of zero yields only this [`Pos<T>`].
+    #[inline]
+    pub fn all_at_chebyshev_distance(&self, distance: T) -> RingIterator<T> {
+        RingIterator {
+            current: self.dest(distance, Direction::TopLeft),
+            directions: [Direction::Right, Direction::Down, Direction::Left, Direction::Up],
+            side_length: distance + distance,
+            side: 0,
+            step: T::zero(),
+        }
+    }
+
+    /// Returns an iterator over `distance` [`Pos<T>`]s in a straight line, starting from this [`Pos<T>`].
+    #[inline]
+    pub fn line_iter(&self, distance: usize, direction: Direction) -> LineIterator<T> {
+        LineIterator::new(*self, distance, direction, T::one())
+    }
+
+    /// Returns an iterator over `distance` [`Pos<T>`]s in a straight line `step` apart, starting from this [`Pos<T>`].
+    /// Returns [`None`] if `step` is zero.
+    #[inline]
+    pub fn line_iter_step(&self, distance: usize, direction: Direction, step: T) -> Option<LineIterator<T>> {
+        if step.is_zero() {
+            return None;
+        }
+        Some(LineIterator::new(*self, distance, direction, step))
+    }
+
+    /// Returns the sum of X and Y.
+    #[inline]
+    pub fn component_sum(&self) -> T {
+        self.x + self.y
+    }
+
+    /// Returns the product of X and Y.
+    #[inline]
+    pub fn component_product(&self) -> T {
+        self.x * self.y
+    }
+
+    /// Returns the [`Pos<T>`] at the fraction `t_num / t_den` of the way from this [`Pos<T>`] to another [`Pos<T>`].
+    /// For integers the result is rounded towards this [`Pos<T>`].
+    #[inline]
+    pub fn lerp(&self, other: &Self, t_num: T, t_den: T) -> Self {
+        Self {
+            x: self.x + (other.x - self.x) * t_num / t_den,
+            y: self.y + (other.y - self.y) * t_num / t_den,
+        }
+    }
+
     /// Returns the [`Pos<T>`] at origin.
     #[inline]
     pub fn origin() -> Self {
         Self { x: T::zero(), y: T::zero() }
     }
 
+    /// Returns whether this [`Pos<T>`] is at origin.
+    #[inline]
+    pub fn is_origin(&self) -> bool {
+        self.x.is_zero() && self.y.is_zero()
+    }
+
     /// Returns the [`Pos<T>`] at unit X .
     #[inline]
     pub fn unit_x() -> Self {
@@ -205,6 +333,80 @@ impl<T: Copy + Num> Pos<T> {
     }
 }
 
+impl<T: Copy + Integer> Pos<T> {
+    /// Returns the midpoint between two [`Pos<T>`]s, rounded down when the coordinates have different parities.
+    #[inline]
+    pub fn midpoint(&self, other: &Self) -> Self {
+        let two = T::one() + T::one();
+        Self { x: (self.x + other.x).div_floor(&two), y: (self.y + other.y).div_floor(&two) }
+    }
+}
+
+impl<T: Copy + Neg<Output = T>> Pos<T> {
+    /// Rotates this [`Pos<T>`] counterclockwise around the origin by 90 degrees.
+    #[inline]
+    pub fn rotate_90(&self) -> Self {
+        Self { x: -self.y, y: self.x }
+    }
+
+    /// Rotates this [`Pos<T>`] around the origin by 180 degrees.
+    #[inline]
+    pub fn rotate_180(&self) -> Self {
+        Self { x: -self.x, y: -self.y }
+    }
+
+    /// Rotates this [`Pos<T>`] counterclockwise around the origin by 270 degrees.
+    #[inline]
+    pub fn rotate_270(&self) -> Self {
+        Self { x: self.y, y: -self.x }
+    }
+
+    /// Rotates this [`Pos<T>`] counterclockwise around the origin by 90 degrees. Alias of [`Pos::rotate_90`].
+    #[inline]
+    pub fn rotate_ccw_90(&self) -> Self {
+        self.rotate_90()
+    }
+
+    /// Rotates this [`Pos<T>`] clockwise around the origin by 90 degrees. Alias of [`Pos::rotate_270`].
+    #[inline]
+    pub fn rotate_cw_90(&self) -> Self {
+        self.rotate_270()
+    }
+}
+
+impl<T: Copy + Num + Neg<Output = T>> Pos<T> {
+    /// Rotates this [`Pos<T>`] clockwise around a centre [`Pos<T>`] by 90 degrees a number of times.
+    #[inline]
+    pub fn rotate_around_cw(&self, center: &Self, times: u32) -> Self {
+        let offset = (0..times % 4).fold(*self - *center, |p, _| p.rotate_cw_90());
+        offset + *center
+    }
+
+    /// Rotates this [`Pos<T>`] counterclockwise around a pivot [`Pos<T>`] by 90 degrees a number of times.
+    #[inline]
+    pub fn rotate_around_ccw(&self, pivot: &Self, turns: u32) -> Self {
+        let offset = *self - *pivot;
+        let rotated = match turns % 4 {
+            0 => offset,
+            1 => offset.rotate_90(),
+            2 => offset.rotate_180(),
+            _ => offset.rotate_270(),
+        };
+        rotated + *pivot
+    }
+}
+
+impl<T: Copy + Num + Ord> Pos<T> {
+    /// Returns an iterator over all [`Pos<T>`]s in the rectangle bounded by this and another [`Pos<T>`], in row-major order.
+    #[inline]
+    pub fn range_to(&self, other: &Self) -> impl Iterator<Item = Self> {
+        let (min_x, max_x) = (self.x.min(other.x), self.x.max(other.x));
+        let (min_y, max_y) = (self.y.min(other.y), self.y.max(other.y));
+        let range = move |min: T, max: T| successors(Some(min), move |&n| if n < max { Some(n + T::one()) } else { None });
+        range(min_y, max_y).flat_map(move |y| range(min_x, max_x).map(move |x| Self { x, y }))
+    }
+}
+
 impl<T: Copy + Num + CheckedAdd + CheckedSub> Pos<T> {
     /// Returns the destination [`Pos<T>`], or [`None`] if arithmetic error.
     #[inline]
@@ -234,9 +436,115 @@ impl<T: Copy + Signed> Pos<T> {
     pub fn abs(&self) -> Self {
         Pos { x: self.x.abs(), y: self.y.abs() }
     }
+
+    /// Returns the [`Pos<T>`]s from this [`Pos<T>`] to another [`Pos<T>`] inclusive, or [`None`] if they are not on the same row or column.
+    #[inline]
+    pub fn interpolate_cardinal(&self, other: &Self) -> Option<impl Iterator<Item = Self>> {
+        if self.x != other.x && self.y != other.y {
+            return None;
+        }
+
+        let (end, step) = (*other, Pos { x: (other.x - self.x).signum(), y: (other.y - self.y).signum() });
+        Some(core::iter::successors(Some(*self), move |&p| if p == end { None } else { Some(p + step) }))
+    }
+
+    /// Returns the scalar projection of this [`Pos<T>`] onto another [`Pos<T>`], or zero if `onto` is the origin.
+    #[inline]
+    pub fn project_scalar(&self, onto: &Self) -> T {
+        let length_sq = onto.dot(onto);
+        if length_sq.is_zero() {
+            return T::zero();
+        }
+        self.dot(onto) / length_sq
+    }
+}
+
+impl<T: Copy + Euclid> Pos<T> {
+    /// Computes the [Euclidean remainder](https://en.wikipedia.org/wiki/Euclidean_division) between this [`Pos<T>`] and another [`Pos<T>`].
+    /// Unlike `%`, the result is never negative.
+    #[inline]
+    pub fn rem_euclid(&self, rhs: &Self) -> Self {
+        Self { x: self.x.rem_euclid(&rhs.x), y: self.y.rem_euclid(&rhs.y) }
+    }
+}
+
+impl<T: Copy + Ord> Pos<T> {
+    /// Returns a [`Pos<T>`] with the smaller X and the smaller Y of two [`Pos<T>`]s.
+    #[inline]
+    pub fn min_components(&self, other: &Self) -> Self {
+        Self { x: self.x.min(other.x), y: self.y.min(other.y) }
+    }
+
+    /// Returns a [`Pos<T>`] with the larger X and the larger Y of two [`Pos<T>`]s.
+    #[inline]
+    pub fn max_components(&self, other: &Self) -> Self {
+        Self { x: self.x.max(other.x), y: self.y.max(other.y) }
+    }
+
+    /// Returns this [`Pos<T>`] with X and Y clamped into an [`Area<T>`].
+    #[inline]
+    pub fn clamp(self, area: &Area<T>) -> Self {
+        Self { x: self.x.clamp(area.min_x, area.max_x), y: self.y.clamp(area.min_y, area.max_y) }
+    }
+}
+
+impl<T: Copy + Ord + Sub<Output = T>> Pos<T> {
+    /// Returns the [Chebyshev distance](https://en.wikipedia.org/wiki/Chebyshev_distance).
+    #[inline]
+    pub fn chebyshev(&self, other: &Self) -> T {
+        let dx = self.x.max(other.x) - self.x.min(other.x);
+        let dy = self.y.max(other.y) - self.y.min(other.y);
+        dx.max(dy)
+    }
+}
+
+impl<T: Copy + PartialOrd + Add<Output = T> + Sub<Output = T> + Mul<Output = T>> Pos<T> {
+    /// Returns the squared [Euclidean distance](https://en.wikipedia.org/wiki/Euclidean_distance).
+    #[inline]
+    pub fn euclidean_sq(&self, other: &Self) -> T {
+        let abs_diff = |a: T, b: T| if a > b { a - b } else { b - a };
+        let (dx, dy) = (abs_diff(self.x, other.x), abs_diff(self.y, other.y));
+        dx * dx + dy * dy
+    }
+}
+
+impl<T: Copy + Add<Output = T> + Sub<Output = T> + Mul<Output = T>> Pos<T> {
+    /// Returns the [dot product](https://en.wikipedia.org/wiki/Dot_product) of two [`Pos<T>`]s.
+    #[inline]
+    pub fn dot(&self, other: &Self) -> T {
+        self.x * other.x + self.y * other.y
+    }
+
+    /// Returns the 2D [cross product](https://en.wikipedia.org/wiki/Cross_product) of two [`Pos<T>`]s, which is positive
+    /// when `other` is counterclockwise from this [`Pos<T>`] and negative when clockwise.
+    #[inline]
+    pub fn cross(&self, other: &Self) -> T {
+        self.x * other.y - self.y * other.x
+    }
+}
+
+#[cfg(feature = "std")]
+impl<T: Float> Pos<T> {
+    /// Returns the [Euclidean distance](https://en.wikipedia.org/wiki/Euclidean_distance).
+    #[inline]
+    pub fn euclidean(&self, other: &Self) -> T {
+        (self.x - other.x).hypot(self.y - other.y)
+    }
 }
 
 impl<T: Copy> Pos<T> {
+    /// Returns X and Y as an array.
+    #[inline]
+    pub fn to_array(&self) -> [T; 2] {
+        [self.x, self.y]
+    }
+
+    /// Returns X and Y as a tuple.
+    #[inline]
+    pub fn to_tuple(&self) -> (T, T) {
+        (self.x, self.y)
+    }
+
     /// Swaps X and Y values.
     #[inline]
     pub fn swap(&self) -> Self {
@@ -263,6 +571,7 @@ impl PosIdx {
 #[cfg(test)]
 mod test {
     use std::format;
+    use std::string::String;
     use super::*;
 
     #[test]
@@ -271,6 +580,19 @@ mod test {
         assert_eq!(format!("{}", sut), "(1, -2)");
     }
 
+    #[test]
+    fn test_default() {
+        let sut: Pos<i32> = Pos::default();
+        assert_eq!(sut, Pos { x: 0, y: 0 });
+        assert_eq!(sut, Pos::origin());
+
+        let sut: Pos<bool> = Pos::default();
+        assert_eq!(sut, Pos { x: false, y: false });
+
+        let sut: Pos<String> = Pos::default();
+        assert_eq!(sut, Pos { x: String::new(), y: String::new() });
+    }
+
     #[test]
     fn test_add() {
         let sut = Pos { x: 1, y: 2 } + Pos { x: 3, y: 4 };
@@ -300,6 +622,21 @@ mod test {
         assert!(sut.is_none());
     }
 
+    #[test]
+    fn test_sum() {
+        let sut: Pos<i32> = vec![Pos { x: 1, y: 2 }, Pos { x: 3, y: 4 }].into_iter().sum();
+        assert_eq!(sut, Pos { x: 4, y: 6 });
+
+        let sut: Pos<i32> = [Pos { x: 1, y: -2 }, Pos { x: -3, y: 4 }, Pos { x: 5, y: 0 }].iter().sum();
+        assert_eq!(sut, Pos { x: 3, y: 2 });
+
+        let sut: Pos<i32> = core::iter::empty::<Pos<i32>>().sum();
+        assert_eq!(sut, Pos::origin());
+
+        let sut: Pos<f64> = [].iter().sum();
+        assert_eq!(sut, Pos { x: 0.0, y: 0.0 });
+    }
+
     #[test]
     fn test_from_direction() {
         assert_eq!(Pos::from(Direction::Up), Pos { x: 0, y: 1 });
@@ -312,6 +649,25 @@ mod test {
         assert_eq!(Pos::from(Direction::BottomRight), Pos { x: 1, y: -1 });
     }
 
+    #[test]
+    fn test_from_array() {
+        assert_eq!(Pos::from([1, 2]), Pos { x: 1, y: 2 });
+        assert_eq!(Pos::from([-3.5, 0.0]), Pos { x: -3.5, y: 0.0 });
+
+        let sut: Pos<u8> = [7, 9].into();
+        assert_eq!(sut, Pos { x: 7, y: 9 });
+    }
+
+    #[test]
+    fn test_into_array() {
+        let sut: [i32; 2] = Pos { x: 1, y: 2 }.into();
+        assert_eq!(sut, [1, 2]);
+
+        let [x, y] = <[i32; 2]>::from(Pos { x: -4, y: 8 });
+        assert_eq!((x, y), (-4, 8));
+        assert_eq!(Pos::from(<[i32; 2]>::from(Pos { x: 5, y: 6 })), Pos { x: 5, y: 6 });
+    }
+
     #[test]
     fn test_sub() {
         let sut = Pos { x: 1, y: 2 } - Pos { x: 3, y: 4 };
@@ -407,6 +763,26 @@ mod test {
         assert_eq!(sut, Pos { x: 1, y: 2 });
     }
 
+    #[test]
+    fn test_rem_scalar() {
+        let sut = Pos { x: 11, y: 25 } % 10;
+        assert_eq!(sut, Pos { x: 1, y: 5 });
+
+        let sut = Pos { x: -11, y: 3 } % 10;
+        assert_eq!(sut, Pos { x: -1, y: 3 });
+    }
+
+    #[test]
+    fn test_rem_assign_scalar() {
+        let mut sut = Pos { x: 11, y: 25 };
+        sut %= 10;
+        assert_eq!(sut, Pos { x: 1, y: 5 });
+
+        let mut sut = Pos { x: -11, y: -20 };
+        sut %= 10;
+        assert_eq!(sut, Pos { x: -1, y: 0 });
+    }
+
     #[test]
     fn test_checked_rem() {
         let sut = Pos { x: 11, y: 13 }.checked_rem(&Pos { x: 10, y: 11 }).unwrap();
@@ -462,12 +838,162 @@ mod test {
         assert_eq!(sut.dest(5, Direction::BottomRight), Pos { x: 5, y: -5 });
     }
 
+    #[test]
+    fn test_manhattan_ring_iter() {
+        let p = Pos { x: 1, y: -1 };
+        let sut: Vec<_> = p.manhattan_ring_iter(1).collect();
+        assert_eq!(sut, vec![Pos { x: 2, y: -1 }, Pos { x: 1, y: 0 }, Pos { x: 0, y: -1 }, Pos { x: 1, y: -2 }]);
+
+        for radius in 1..10 {
+            let sut: Vec<_> = p.manhattan_ring_iter(radius).collect();
+            assert_eq!(sut.len() as i32, 4 * radius);
+            assert!(sut.iter().all(|other| p.manhattan(other) == radius));
+        }
+
+        let sut: Vec<_> = p.manhattan_ring_iter(0).collect();
+        assert_eq!(sut, vec![p]);
+    }
+
+    #[test]
+    fn test_taxicab_circle_iter() {
+        let p = Pos { x: 3, y: 4 };
+        assert!(p.taxicab_circle_iter(5).eq(p.manhattan_ring_iter(5)));
+
+        let p = Pos { x: -2, y: 7 };
+        for radius in 1..8 {
+            let sut: Vec<_> = p.taxicab_circle_iter(radius).collect();
+            assert_eq!(sut.len() as i32, 4 * radius);
+            assert!(sut.iter().all(|other| p.manhattan(other) == radius));
+        }
+        assert_eq!(p.taxicab_circle_iter(0).collect::<Vec<_>>(), vec![p]);
+    }
+
+    #[test]
+    fn test_all_at_chebyshev_distance() {
+        let p = Pos { x: 1, y: -1 };
+        let sut: Vec<_> = p.all_at_chebyshev_distance(1).collect();
+        assert_eq!(sut, vec![
+            Pos { x: 0, y: 0 },
+            Pos { x: 1, y: 0 },
+            Pos { x: 2, y: 0 },
+            Pos { x: 2, y: -1 },
+            Pos { x: 2, y: -2 },
+            Pos { x: 1, y: -2 },
+            Pos { x: 0, y: -2 },
+            Pos { x: 0, y: -1 },
+        ]);
+
+        for distance in 1..10 {
+            let sut: Vec<_> = p.all_at_chebyshev_distance(distance).collect();
+            assert_eq!(sut.len() as i32, 8 * distance);
+            assert!(sut.iter().all(|other| p.chebyshev(other) == distance));
+            assert!(sut.windows(2).all(|w| w[0].manhattan(&w[1]) == 1));
+            assert_eq!(sut[0].manhattan(&sut[sut.len() - 1]), 1);
+        }
+
+        let sut: Vec<_> = p.all_at_chebyshev_distance(0).collect();
+        assert_eq!(sut, vec![p]);
+    }
+
+    #[test]
+    fn test_line_iter() {
+        let p = Pos { x: 1, y: -1 };
+        let sut: Vec<_> = p.line_iter(3, Direction::Up).collect();
+        assert_eq!(sut, vec![Pos { x: 1, y: -1 }, Pos { x: 1, y: 0 }, Pos { x: 1, y: 1 }]);
+
+        let sut: Vec<_> = p.line_iter(4, Direction::BottomRight).rev().collect();
+        assert_eq!(sut, vec![Pos { x: 4, y: -4 }, Pos { x: 3, y: -3 }, Pos { x: 2, y: -2 }, Pos { x: 1, y: -1 }]);
+
+        for direction in Direction::all() {
+            let forward: Vec<_> = p.line_iter(5, direction).collect();
+            let mut backward: Vec<_> = p.line_iter(5, direction).rev().collect();
+            backward.reverse();
+            assert_eq!(forward, backward);
+            assert_eq!(forward.len(), 5);
+        }
+
+        assert_eq!(p.line_iter(1, Direction::Left).collect::<Vec<_>>(), vec![p]);
+        assert_eq!(p.line_iter(0, Direction::Left).count(), 0);
+
+        let p: PosIdx = Pos { x: 2, y: 2 };
+        assert_eq!(p.line_iter(3, Direction::BottomLeft).rev().collect::<Vec<_>>(), vec![Pos { x: 0, y: 0 }, Pos { x: 1, y: 1 }, p]);
+        assert_eq!(Pos { x: 1usize, y: 0 }.line_iter(3, Direction::Left).next(), Some(Pos { x: 1, y: 0 }));
+        assert_eq!(p.line_iter(3, Direction::Up).len(), 3);
+    }
+
+    #[test]
+    fn test_line_iter_step() {
+        let p = Pos { x: 1, y: -1 };
+        let sut: Vec<_> = p.line_iter_step(4, Direction::Right, 2).unwrap().collect();
+        assert_eq!(sut, vec![Pos { x: 1, y: -1 }, Pos { x: 3, y: -1 }, Pos { x: 5, y: -1 }, Pos { x: 7, y: -1 }]);
+
+        let sut: Vec<_> = p.line_iter_step(3, Direction::BottomLeft, 3).unwrap().rev().collect();
+        assert_eq!(sut, vec![Pos { x: -5, y: -7 }, Pos { x: -2, y: -4 }, Pos { x: 1, y: -1 }]);
+
+        for direction in Direction::all() {
+            assert!(p.line_iter_step(6, direction, 1).unwrap().eq(p.line_iter(6, direction)));
+            assert_eq!(p.line_iter_step(6, direction, 2).unwrap().count(), 6);
+        }
+
+        assert_eq!(p.line_iter_step(0, Direction::Up, 2).unwrap().count(), 0);
+        assert!(p.line_iter_step(3, Direction::Up, 0).is_none());
+    }
+
+    #[test]
+    fn test_component_sum() {
+        assert_eq!(Pos { x: 3, y: 4 }.component_sum(), 7);
+        assert_eq!(Pos { x: -3, y: -4 }.component_sum(), -7);
+        assert_eq!(Pos { x: -3, y: 4 }.component_sum(), 1);
+        assert_eq!(Pos { x: 0, y: 0 }.component_sum(), 0);
+    }
+
+    #[test]
+    fn test_component_product() {
+        assert_eq!(Pos { x: 3, y: 4 }.component_product(), 12);
+        assert_eq!(Pos { x: -3, y: -4 }.component_product(), 12);
+        assert_eq!(Pos { x: -3, y: 4 }.component_product(), -12);
+        assert_eq!(Pos { x: 0, y: 5 }.component_product(), 0);
+    }
+
+    #[test]
+    fn test_midpoint() {
+        let sut = Pos { x: 2, y: -4 };
+        assert_eq!(sut.midpoint(&sut), sut);
+        assert_eq!(sut.midpoint(&Pos { x: 6, y: 8 }), Pos { x: 4, y: 2 });
+        assert_eq!(sut.midpoint(&Pos { x: 5, y: 1 }), Pos { x: 3, y: -2 });
+        assert_eq!(Pos { x: -3, y: -1 }.midpoint(&Pos { x: 0, y: 0 }), Pos { x: -2, y: -1 });
+        assert_eq!(Pos { x: 1, y: 3 }.midpoint(&Pos { x: 5, y: 7 }), Pos { x: 3, y: 5 });
+        assert_eq!(Pos { x: 0usize, y: 0 }.midpoint(&Pos { x: 7, y: 1 }), Pos { x: 3, y: 0 });
+    }
+
+    #[test]
+    fn test_lerp() {
+        let (a, b) = (Pos { x: 2, y: -4 }, Pos { x: 10, y: 8 });
+        assert_eq!(a.lerp(&b, 0, 4), a);
+        assert_eq!(a.lerp(&b, 4, 4), b);
+        assert_eq!(a.lerp(&b, 1, 2), Pos { x: 6, y: 2 });
+        assert_eq!(a.lerp(&b, 1, 4), Pos { x: 4, y: -1 });
+        assert_eq!(a.lerp(&b, 1, 3), Pos { x: 4, y: 0 });
+        assert_eq!(b.lerp(&a, 1, 3), Pos { x: 8, y: 4 });
+        assert_eq!(a.lerp(&b, 3, 2), Pos { x: 14, y: 14 });
+        assert_eq!(Pos { x: 0.0, y: 1.0 }.lerp(&Pos { x: 1.0, y: 0.0 }, 0.25, 1.0), Pos { x: 0.25, y: 0.75 });
+    }
+
     #[test]
     fn test_origin() {
         let sut: Pos<i32> = Pos::origin();
         assert_eq!(sut, Pos { x: 0, y: 0 });
     }
 
+    #[test]
+    fn test_is_origin() {
+        assert!(Pos::<i32>::origin().is_origin());
+        assert!(!Pos::<i32>::unit_x().is_origin());
+        assert!(!Pos::<i32>::unit_y().is_origin());
+        assert!(!Pos { x: -1, y: 1 }.is_origin());
+        assert!((Pos { x: 3, y: -2 } - Pos { x: 3, y: -2 }).is_origin());
+    }
+
     #[test]
     fn test_unit_x() {
         let sut: Pos<i32> = Pos::unit_x();
@@ -480,6 +1006,104 @@ mod test {
         assert_eq!(sut, Pos { x: 0, y: 1 });
     }
 
+    #[test]
+    fn test_rotate_90() {
+        let p = Pos { x: 3, y: 1 };
+        assert_eq!(p.rotate_90(), Pos { x: -1, y: 3 });
+        assert_eq!(p.rotate_90().rotate_90(), Pos { x: -3, y: -1 });
+        assert_eq!(p.rotate_90().rotate_90().rotate_90().rotate_90(), p);
+        assert_eq!(Pos { x: 0, y: 0 }.rotate_90(), Pos { x: 0, y: 0 });
+    }
+
+    #[test]
+    fn test_rotate_180() {
+        let p = Pos { x: 3, y: 1 };
+        assert_eq!(p.rotate_180(), Pos { x: -3, y: -1 });
+        assert_eq!(p.rotate_180(), p.rotate_90().rotate_90());
+        assert_eq!(p.rotate_180().rotate_180(), p);
+    }
+
+    #[test]
+    fn test_rotate_270() {
+        let p = Pos { x: 3, y: 1 };
+        assert_eq!(p.rotate_270(), Pos { x: 1, y: -3 });
+        assert_eq!(p.rotate_270(), p.rotate_90().rotate_90().rotate_90());
+        assert_eq!(p.rotate_270().rotate_90(), p);
+    }
+
+    #[test]
+    fn test_rotate_ccw_90() {
+        let p = Pos { x: -2, y: 5 };
+        assert_eq!(p.rotate_ccw_90(), p.rotate_90());
+        assert_eq!(p.rotate_ccw_90(), Pos { x: -5, y: -2 });
+    }
+
+    #[test]
+    fn test_rotate_cw_90() {
+        let p = Pos { x: -2, y: 5 };
+        assert_eq!(p.rotate_cw_90(), p.rotate_270());
+        assert_eq!(p.rotate_cw_90(), Pos { x: 5, y: 2 });
+        assert_eq!(Pos { x: 0, y: 1 }.rotate_cw_90(), Pos { x: 1, y: 0 });
+    }
+
+    #[test]
+    fn test_rotate_around_cw() {
+        let center = Pos { x: 2, y: 3 };
+        assert_eq!(center.rotate_around_cw(&center, 1), center);
+
+        let p = Pos { x: 4, y: 4 };
+        assert_eq!(p.rotate_around_cw(&center, 1), Pos { x: 3, y: 1 });
+        assert_eq!(p.rotate_around_cw(&center, 2), Pos { x: 0, y: 2 });
+        assert_eq!(p.rotate_around_cw(&center, 3), Pos { x: 1, y: 5 });
+        assert_eq!(p.rotate_around_cw(&center, 4), p);
+        assert_eq!(p.rotate_around_cw(&center, 0), p);
+        assert_eq!(p.rotate_around_cw(&center, 5), p.rotate_around_cw(&center, 1));
+
+        let center = Pos { x: 0, y: 0 };
+        assert_eq!(Pos { x: 1, y: 1 }.rotate_around_cw(&center, 1), Pos { x: 1, y: -1 });
+        assert_eq!(Pos { x: 1, y: -1 }.rotate_around_cw(&center, 1), Pos { x: -1, y: -1 });
+        assert_eq!(Pos { x: -1, y: -1 }.rotate_around_cw(&center, 1), Pos { x: -1, y: 1 });
+        assert_eq!(Pos { x: -1, y: 1 }.rotate_around_cw(&center, 1), Pos { x: 1, y: 1 });
+    }
+
+    #[test]
+    fn test_rotate_around_ccw() {
+        let pivot = Pos { x: 1, y: 1 };
+        assert_eq!(pivot.rotate_around_ccw(&pivot, 1), pivot);
+        assert_eq!(pivot.rotate_around_ccw(&pivot, 3), pivot);
+
+        let corner = Pos { x: 2, y: 0 };
+        assert_eq!(corner.rotate_around_ccw(&pivot, 1), Pos { x: 2, y: 2 });
+        assert_eq!(corner.rotate_around_ccw(&pivot, 2), Pos { x: 0, y: 2 });
+        assert_eq!(corner.rotate_around_ccw(&pivot, 3), Pos { x: 0, y: 0 });
+        assert_eq!(corner.rotate_around_ccw(&pivot, 4), corner);
+        assert_eq!(corner.rotate_around_ccw(&pivot, 6), corner.rotate_around_ccw(&pivot, 2));
+
+        let p = Pos { x: 7, y: -3 };
+        let pivot = Pos { x: -2, y: 4 };
+        for turns in 0..8 {
+            assert_eq!(p.rotate_around_ccw(&pivot, turns), p.rotate_around_cw(&pivot, (4 - turns % 4) % 4));
+        }
+    }
+
+    #[test]
+    fn test_range_to() {
+        let sut: Vec<_> = Pos { x: 1, y: 1 }.range_to(&Pos { x: -1, y: 0 }).collect();
+        assert_eq!(sut, vec![
+            Pos { x: -1, y: 0 },
+            Pos { x: 0, y: 0 },
+            Pos { x: 1, y: 0 },
+            Pos { x: -1, y: 1 },
+            Pos { x: 0, y: 1 },
+            Pos { x: 1, y: 1 },
+        ]);
+
+        assert_eq!(Pos { x: 0, y: 0 }.range_to(&Pos { x: 4, y: 9 }).count(), 50);
+        assert_eq!(Pos { x: 3, y: 3 }.range_to(&Pos { x: 3, y: 3 }).collect::<Vec<_>>(), vec![Pos { x: 3, y: 3 }]);
+        assert_eq!(Pos { x: 2usize, y: 0 }.range_to(&Pos { x: 0, y: 1 }).count(), 6);
+        assert_eq!(Pos { x: u8::MAX, y: u8::MAX }.range_to(&Pos { x: u8::MAX - 1, y: u8::MAX }).count(), 2);
+    }
+
     #[test]
     fn test_manhattan() {
         let p = Pos { x: 1, y: 2 };
@@ -490,6 +1114,100 @@ mod test {
         assert_eq!(p.manhattan(&Pos { x: -45, y: 9 }), 55);
     }
 
+    #[test]
+    fn test_rem_euclid() {
+        let size = Pos { x: 11, y: 7 };
+        assert_eq!(Pos { x: 13, y: 6 }.rem_euclid(&size), Pos { x: 2, y: 6 });
+        assert_eq!(Pos { x: -1, y: -8 }.rem_euclid(&size), Pos { x: 10, y: 6 });
+        assert_eq!(Pos { x: -1, y: -8 } % size, Pos { x: -1, y: -1 });
+        assert_eq!(Pos { x: -22, y: 0 }.rem_euclid(&size), Pos { x: 0, y: 0 });
+        assert_eq!(Pos { x: -3, y: 3 }.rem_euclid(&Pos { x: -2, y: -2 }), Pos { x: 1, y: 1 });
+    }
+
+    #[test]
+    fn test_min_components() {
+        let p = Pos { x: 5, y: 1 };
+        assert_eq!(p.min_components(&Pos { x: 2, y: 7 }), Pos { x: 2, y: 1 });
+        assert_eq!(p.min_components(&Pos { x: 8, y: 7 }), Pos { x: 5, y: 1 });
+        assert_eq!(p.min_components(&Pos { x: 2, y: -3 }), Pos { x: 2, y: -3 });
+        assert_eq!(p.min_components(&Pos { x: 8, y: -3 }), Pos { x: 5, y: -3 });
+        assert_eq!(p.min_components(&p), p);
+    }
+
+    #[test]
+    fn test_max_components() {
+        let p = Pos { x: 5, y: 1 };
+        assert_eq!(p.max_components(&Pos { x: 2, y: 7 }), Pos { x: 5, y: 7 });
+        assert_eq!(p.max_components(&Pos { x: 8, y: 7 }), Pos { x: 8, y: 7 });
+        assert_eq!(p.max_components(&Pos { x: 2, y: -3 }), Pos { x: 5, y: 1 });
+        assert_eq!(p.max_components(&Pos { x: 8, y: -3 }), Pos { x: 8, y: 1 });
+        assert_eq!(p.max_components(&p), p);
+    }
+
+    #[test]
+    fn test_clamp() {
+        let area = Area { max_x: 5, max_y: 3, min_x: -2, min_y: 0 };
+        assert_eq!(Pos { x: 1, y: 2 }.clamp(&area), Pos { x: 1, y: 2 });
+        assert_eq!(Pos { x: 5, y: 0 }.clamp(&area), Pos { x: 5, y: 0 });
+        assert_eq!(Pos { x: -10, y: 10 }.clamp(&area), Pos { x: -2, y: 3 });
+        assert_eq!(Pos { x: 10, y: 10 }.clamp(&area), Pos { x: 5, y: 3 });
+        assert_eq!(Pos { x: -10, y: -10 }.clamp(&area), Pos { x: -2, y: 0 });
+        assert_eq!(Pos { x: 10, y: -10 }.clamp(&area), Pos { x: 5, y: 0 });
+        assert_eq!(Pos { x: 3, y: -1 }.clamp(&area), Pos { x: 3, y: 0 });
+    }
+
+    #[test]
+    fn test_chebyshev() {
+        let p = Pos { x: 0, y: 0 };
+        assert_eq!(p.chebyshev(&Pos { x: 3, y: 5 }), 5);
+        assert_eq!(p.chebyshev(&Pos { x: -7, y: 2 }), 7);
+        assert_eq!(p.chebyshev(&p), 0);
+
+        let p = Pos { x: -1, y: -2 };
+        assert_eq!(p.chebyshev(&Pos { x: -4, y: 9 }), 11);
+        assert_eq!(Pos { x: -4, y: 9 }.chebyshev(&p), 11);
+
+        let p: PosIdx = Pos { x: 2, y: 8 };
+        assert_eq!(p.chebyshev(&Pos { x: 5, y: 1 }), 7);
+        assert_eq!(p.chebyshev(&p), 0);
+    }
+
+    #[test]
+    fn test_euclidean_sq() {
+        let p = Pos { x: 0, y: 0 };
+        assert_eq!(p.euclidean_sq(&Pos { x: 3, y: 4 }), 25);
+        assert_eq!(Pos { x: 3, y: 4 }.euclidean_sq(&p), 25);
+        assert_eq!(p.euclidean_sq(&p), 0);
+
+        let p = Pos { x: -1, y: 2 };
+        assert_eq!(p.euclidean_sq(&Pos { x: 4, y: -10 }), 169);
+        assert_eq!(Pos { x: 4, y: -10 }.euclidean_sq(&p), 169);
+        assert_eq!(Pos { x: 1.5, y: 0.5 }.euclidean_sq(&Pos { x: 0.0, y: 2.5 }), 6.25);
+
+        let p: PosIdx = Pos { x: 0, y: 0 };
+        assert_eq!(p.euclidean_sq(&Pos { x: 3, y: 4 }), 25);
+        assert_eq!(Pos { x: 3, y: 4 }.euclidean_sq(&p), 25);
+        assert_eq!(Pos { x: 5, y: 1 }.euclidean_sq(&Pos { x: 2, y: 7 }), 45);
+    }
+
+    #[test]
+    fn test_dot() {
+        assert_eq!(Pos { x: 1, y: 2 }.dot(&Pos { x: 3, y: 4 }), 11);
+        assert_eq!(Pos { x: -2, y: 5 }.dot(&Pos { x: 3, y: -1 }), -11);
+        assert_eq!(Pos { x: 3, y: 1 }.dot(&Pos { x: -1, y: 3 }), 0);
+        assert_eq!(Pos { x: 3, y: 1 }.dot(&Pos { x: 3, y: 1 }.rotate_90()), 0);
+    }
+
+    #[test]
+    fn test_cross() {
+        let p = Pos { x: 2, y: 1 };
+        assert_eq!(p.cross(&Pos { x: 1, y: 3 }), 5);
+        assert!(p.cross(&p.rotate_90()) > 0);
+        assert!(p.cross(&p.rotate_cw_90()) < 0);
+        assert_eq!(p.cross(&Pos { x: -4, y: -2 }), 0);
+        assert_eq!(p.cross(&Pos { x: 1, y: 3 }), -Pos { x: 1, y: 3 }.cross(&p));
+    }
+
     #[test]
     fn test_abs() {
         let sut = Pos { x: 1, y: 2 };
@@ -499,6 +1217,40 @@ mod test {
         assert_eq!(sut.abs(), Pos { x: 1, y: 2 });
     }
 
+    #[test]
+    fn test_interpolate_cardinal() {
+        let p = Pos { x: 1, y: 2 };
+        let sut: Vec<_> = p.interpolate_cardinal(&Pos { x: 1, y: -1 }).unwrap().collect();
+        assert_eq!(sut, vec![Pos { x: 1, y: 2 }, Pos { x: 1, y: 1 }, Pos { x: 1, y: 0 }, Pos { x: 1, y: -1 }]);
+
+        let other = Pos { x: 6, y: 2 };
+        let sut: Vec<_> = p.interpolate_cardinal(&other).unwrap().collect();
+        assert_eq!(sut.len() as i32, p.manhattan(&other) + 1);
+        assert_eq!(sut.first(), Some(&p));
+        assert_eq!(sut.last(), Some(&other));
+
+        let sut: Vec<_> = other.interpolate_cardinal(&p).unwrap().collect();
+        assert_eq!(sut, vec![Pos { x: 6, y: 2 }, Pos { x: 5, y: 2 }, Pos { x: 4, y: 2 }, Pos { x: 3, y: 2 }, Pos { x: 2, y: 2 }, Pos { x: 1, y: 2 }]);
+
+        let sut: Vec<_> = p.interpolate_cardinal(&p).unwrap().collect();
+        assert_eq!(sut, vec![p]);
+
+        assert!(p.interpolate_cardinal(&Pos { x: 2, y: 3 }).is_none());
+    }
+
+    #[test]
+    fn test_project_scalar() {
+        let sut = Pos { x: 3, y: 0 };
+        assert_eq!(sut.project_scalar(&Pos { x: 3, y: 0 }), 1);
+        assert_eq!(sut.project_scalar(&Pos { x: 0, y: 5 }), 0);
+        assert_eq!(sut.project_scalar(&Pos { x: -3, y: 0 }), -1);
+        assert_eq!(sut.project_scalar(&Pos { x: 0, y: 0 }), 0);
+
+        let sut = Pos { x: 4, y: 4 };
+        assert_eq!(sut.project_scalar(&Pos { x: 2, y: 2 }), 2);
+        assert_eq!(sut.project_scalar(&Pos { x: -1, y: 1 }), 0);
+    }
+
     #[test]
     fn test_checked_dest() {
         let sut: Pos<usize> = Pos { x: 0, y: 0 };
@@ -512,6 +1264,18 @@ mod test {
         assert_eq!(sut.checked_dest(5, Direction::BottomRight), None);
     }
 
+    #[test]
+    fn test_euclidean() {
+        let p = Pos { x: 0.0, y: 0.0 };
+        assert_eq!(p.euclidean(&Pos { x: 3.0, y: 4.0 }), 5.0);
+        assert_eq!(p.euclidean(&Pos { x: -3.0, y: -4.0 }), 5.0);
+        assert_eq!(p.euclidean(&p), 0.0);
+
+        let p = Pos { x: 1.5, y: -2.0 };
+        let other = Pos { x: -4.25, y: 7.5 };
+        assert_eq!(p.euclidean(&other), other.euclidean(&p));
+    }
+
     #[test]
     fn test_swap() {
         let sut = Pos { x: 1, y: 2 };
@@ -521,6 +1285,19 @@ mod test {
         assert_eq!(sut.swap(), Pos { x: 1, y: -2 });
     }
 
+    #[test]
+    fn test_to_array() {
+        let sut = Pos { x: 3, y: -1 };
+        assert_eq!(sut.to_array(), [3, -1]);
+        assert_eq!(Pos::from(sut.to_array()), sut);
+    }
+
+    #[test]
+    fn test_to_tuple() {
+        let sut = Pos { x: 3, y: -1 };
+        assert_eq!(sut.to_tuple(), (3, -1));
+    }
+
     #[test]
     fn test_with_same() {
         let sut: Pos<i32> = Pos::with_same(3);
@@ -0,0 +1,151 @@
+use core::fmt::{Display, Formatter};
+use core::ops::{Add, Sub};
+use num::{Integer, Num, Signed};
+
+/// A position in a 3D space.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Pos3<T> {
+    pub x: T,
+    pub y: T,
+    pub z: T,
+}
+
+impl<T: Display> Display for Pos3<T> {
+    #[inline]
+    fn fmt(&self, f: &mut Formatter<'_>) -> core::fmt::Result {
+        write!(f, "({}, {}, {})", self.x, self.y, self.z)
+    }
+}
+
+impl<T: Add<Output = T>> Add for Pos3<T> {
+    type Output = Self;
+
+    #[inline]
+    fn add(self, rhs: Self) -> Self::Output {
+        Self { x: self.x + rhs.x, y: self.y + rhs.y, z: self.z + rhs.z }
+    }
+}
+
+impl<T: Sub<Output = T>> Sub for Pos3<T> {
+    type Output = Self;
+
+    #[inline]
+    fn sub(self, rhs: Self) -> Self::Output {
+        Self { x: self.x - rhs.x, y: self.y - rhs.y, z: self.z - rhs.z }
+    }
+}
+
+impl<T> Pos3<T> {
+    /// Returns a new [`Pos3<T>`].
+    #[inline]
+    pub fn new(x: T, y: T, z: T) -> Self {
+        Self { x, y, z }
+    }
+}
+
+impl<T: Copy + Num> Pos3<T> {
+    /// Returns all 26 neighbouring [`Pos3<T>`]s sharing a face, an edge or a vertex with this [`Pos3<T>`].
+    pub fn neighbours_all_26(&self) -> [Self; 26] {
+        let xs = [self.x - T::one(), self.x, self.x + T::one()];
+        let ys = [self.y - T::one(), self.y, self.y + T::one()];
+        let zs = [self.z - T::one(), self.z, self.z + T::one()];
+
+        let mut result = [*self; 26];
+        let mut i = 0;
+        for z in zs {
+            for y in ys {
+                for x in xs {
+                    let pos = Self { x, y, z };
+                    if pos != *self {
+                        result[i] = pos;
+                        i += 1;
+                    }
+                }
+            }
+        }
+        result
+    }
+}
+
+impl<T: Copy + Signed + Ord> Pos3<T> {
+    /// Returns the [Chebyshev distance](https://en.wikipedia.org/wiki/Chebyshev_distance) between two [`Pos3<T>`]s.
+    #[inline]
+    pub fn chebyshev(&self, other: &Self) -> T {
+        (self.x - other.x).abs().max((self.y - other.y).abs()).max((self.z - other.z).abs())
+    }
+}
+
+impl<T: Copy + Integer + Signed> Pos3<T> {
+    /// Returns whether two [`Pos3<T>`] cubes share a face.
+    #[inline]
+    pub fn is_face_adjacent(&self, other: &Self) -> bool {
+        (self.x - other.x).abs() + (self.y - other.y).abs() + (self.z - other.z).abs() == T::one()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use std::collections::HashSet;
+    use std::format;
+    use super::*;
+
+    #[test]
+    fn test_display() {
+        let sut = Pos3 { x: 1, y: -2, z: 3 };
+        assert_eq!(format!("{}", sut), "(1, -2, 3)");
+    }
+
+    #[test]
+    fn test_add() {
+        let sut = Pos3 { x: 1, y: 2, z: 3 } + Pos3 { x: 4, y: -5, z: 6 };
+        assert_eq!(sut, Pos3 { x: 5, y: -3, z: 9 });
+    }
+
+    #[test]
+    fn test_sub() {
+        let sut = Pos3 { x: 1, y: 2, z: 3 } - Pos3 { x: 4, y: -5, z: 6 };
+        assert_eq!(sut, Pos3 { x: -3, y: 7, z: -3 });
+    }
+
+    #[test]
+    fn test_new() {
+        let sut = Pos3::new(1, 2, 3);
+        assert_eq!(sut, Pos3 { x: 1, y: 2, z: 3 });
+    }
+
+    #[test]
+    fn test_neighbours_all_26() {
+        let p: Pos3<i32> = Pos3 { x: 1, y: -1, z: 5 };
+        let sut = p.neighbours_all_26();
+        let unique: HashSet<_> = sut.iter().collect();
+        assert_eq!(unique.len(), 26);
+        assert!(!sut.contains(&p));
+        assert!(sut.iter().all(|n| (n.x - p.x).abs() <= 1 && (n.y - p.y).abs() <= 1 && (n.z - p.z).abs() <= 1));
+        assert!(sut.contains(&Pos3 { x: 0, y: -2, z: 4 }));
+        assert!(sut.contains(&Pos3 { x: 2, y: 0, z: 6 }));
+        assert!(sut.contains(&Pos3 { x: 1, y: -1, z: 6 }));
+    }
+
+    #[test]
+    fn test_chebyshev() {
+        let sut = Pos3 { x: 1, y: -2, z: 3 };
+        assert_eq!(sut.chebyshev(&sut), 0);
+        assert_eq!(sut.chebyshev(&Pos3 { x: 2, y: -1, z: 4 }), 1);
+        assert_eq!(sut.chebyshev(&Pos3 { x: -4, y: 0, z: 5 }), 5);
+        assert_eq!(sut.chebyshev(&Pos3 { x: 1, y: -2, z: -7 }), 10);
+        assert!(sut.neighbours_all_26().iter().all(|n| n.chebyshev(&sut) == 1));
+    }
+
+    #[test]
+    fn test_is_face_adjacent() {
+        let sut = Pos3 { x: 1, y: -1, z: 5 };
+        let neighbours = sut.neighbours_all_26();
+        assert_eq!(neighbours.iter().filter(|n| n.is_face_adjacent(&sut)).count(), 6);
+        assert!(sut.is_face_adjacent(&Pos3 { x: 1, y: -1, z: 4 }));
+        assert!(sut.is_face_adjacent(&Pos3 { x: 0, y: -1, z: 5 }));
+        assert!(!sut.is_face_adjacent(&Pos3 { x: 2, y: 0, z: 5 }));
+        assert!(!sut.is_face_adjacent(&Pos3 { x: 2, y: 0, z: 6 }));
+        assert!(!sut.is_face_adjacent(&Pos3 { x: 1, y: -1, z: 7 }));
+        assert!(!sut.is_face_adjacent(&sut));
+    }
+}
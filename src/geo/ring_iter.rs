@@ -0,0 +1,83 @@
+use num::Num;
+use crate::geo::direction::Direction;
+use crate::geo::pos::Pos;
+
+/// Represents an iterator that walks the 4 sides of a ring of [`Pos<T>`]s.
+#[derive(Clone, Debug, Eq, Hash, PartialEq)]
+pub struct RingIterator<T> {
+    pub(crate) current: Pos<T>,
+    pub(crate) directions: [Direction; 4],
+    pub(crate) side_length: T,
+    pub(crate) side: usize,
+    pub(crate) step: T,
+}
+
+impl<T: Copy + Num + PartialOrd> Iterator for RingIterator<T> {
+    type Item = Pos<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.side >= self.directions.len() || self.side_length < T::zero() {
+            return None;
+        }
+
+        let result = self.current;
+        if self.side_length.is_zero() {
+            self.side = self.directions.len();
+            return Some(result);
+        }
+
+        self.current = self.current.dest(T::one(), self.directions[self.side]);
+        self.step = self.step + T::one();
+        if self.step == self.side_length {
+            self.step = T::zero();
+            self.side += 1;
+        }
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_iter() {
+        let iter = RingIterator {
+            current: Pos { x: 0, y: 0 },
+            directions: [Direction::Right, Direction::Up, Direction::Left, Direction::Down],
+            side_length: 2,
+            side: 0,
+            step: 0,
+        };
+        let sut: Vec<_> = iter.collect();
+        assert_eq!(sut, vec![
+            Pos { x: 0, y: 0 },
+            Pos { x: 1, y: 0 },
+            Pos { x: 2, y: 0 },
+            Pos { x: 2, y: 1 },
+            Pos { x: 2, y: 2 },
+            Pos { x: 1, y: 2 },
+            Pos { x: 0, y: 2 },
+            Pos { x: 0, y: 1 },
+        ]);
+
+        let iter = RingIterator {
+            current: Pos { x: 5, y: 5 },
+            directions: [Direction::Right, Direction::Up, Direction::Left, Direction::Down],
+            side_length: 0,
+            side: 0,
+            step: 0,
+        };
+        let sut: Vec<_> = iter.collect();
+        assert_eq!(sut, vec![Pos { x: 5, y: 5 }]);
+
+        let iter = RingIterator {
+            current: Pos { x: 5, y: 5 },
+            directions: [Direction::Right, Direction::Up, Direction::Left, Direction::Down],
+            side_length: -1,
+            side: 0,
+            step: 0,
+        };
+        assert_eq!(iter.count(), 0);
+    }
+}
@@ -0,0 +1,203 @@
+#![cfg(feature = "std")]
+
+use std::collections::HashMap;
+use crate::geo::area::Area;
+use crate::geo::direction::Direction;
+use crate::geo::pos::Pos;
+
+/// Represents an unbounded 2D grid which only stores occupied cells.
+#[derive(Clone, Debug, Default, Eq, PartialEq)]
+pub struct SparseGrid<T> {
+    pub cells: HashMap<Pos<i32>, T>,
+}
+
+impl<T> SparseGrid<T> {
+    /// Returns a new empty [`SparseGrid<T>`].
+    #[inline]
+    pub fn new() -> Self {
+        Self { cells: HashMap::new() }
+    }
+
+    /// Returns the number of occupied cells.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Returns the number of active cells, which is the same as [`SparseGrid::len`].
+    #[inline]
+    pub fn count_active(&self) -> usize {
+        self.cells.len()
+    }
+
+    /// Returns whether this [`SparseGrid<T>`] has no occupied cells.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.cells.is_empty()
+    }
+
+    /// Returns the [`Area<i32>`] bounding all occupied cells, or [`None`] if there are none.
+    #[inline]
+    pub fn area(&self) -> Option<Area<i32>> {
+        Area::from_positions(self.cells.keys().copied())
+    }
+
+    /// Returns the ratio of occupied cells to the size of the bounding [`Area<i32>`].
+    #[inline]
+    pub fn load_factor(&self) -> f64 {
+        self.area().map_or(0.0, |area| self.len() as f64 / area.size() as f64)
+    }
+
+    /// Returns the value at a [`Pos<i32>`], or [`None`] if the cell is not occupied.
+    #[inline]
+    pub fn get(&self, pos: &Pos<i32>) -> Option<&T> {
+        self.cells.get(pos)
+    }
+
+    /// Inserts a value at a [`Pos<i32>`], returning the previous value if the cell was occupied.
+    #[inline]
+    pub fn insert(&mut self, pos: Pos<i32>, value: T) -> Option<T> {
+        self.cells.insert(pos, value)
+    }
+
+    /// Removes the value at a [`Pos<i32>`], returning it if the cell was occupied.
+    #[inline]
+    pub fn remove(&mut self, pos: &Pos<i32>) -> Option<T> {
+        self.cells.remove(pos)
+    }
+
+    /// Returns the occupied cross neighbours of a [`Pos<i32>`].
+    pub fn neighbours(&self, pos: &Pos<i32>) -> Vec<(Pos<i32>, &T)> {
+        Direction::cross()
+            .into_iter()
+            .map(|dir| pos.dest(1, dir))
+            .filter_map(|p| self.cells.get(&p).map(|v| (p, v)))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_new() {
+        let sut: SparseGrid<u8> = SparseGrid::new();
+        assert!(sut.cells.is_empty());
+    }
+
+    #[test]
+    fn test_len() {
+        let mut sut = SparseGrid::new();
+        assert_eq!(sut.len(), 0);
+
+        sut.insert(Pos { x: 0, y: 0 }, 'a');
+        sut.insert(Pos { x: 3, y: 1 }, 'b');
+        assert_eq!(sut.len(), 2);
+
+        sut.insert(Pos { x: 3, y: 1 }, 'c');
+        assert_eq!(sut.len(), 2);
+    }
+
+    #[test]
+    fn test_count_active() {
+        let mut sut = SparseGrid::new();
+        assert_eq!(sut.count_active(), 0);
+
+        sut.insert(Pos { x: 0, y: 0 }, 'a');
+        sut.insert(Pos { x: -2, y: 5 }, 'b');
+        assert_eq!(sut.count_active(), 2);
+
+        sut.remove(&Pos { x: 0, y: 0 });
+        assert_eq!(sut.count_active(), 1);
+
+        sut.remove(&Pos { x: 0, y: 0 });
+        assert_eq!(sut.count_active(), 1);
+    }
+
+    #[test]
+    fn test_is_empty() {
+        let mut sut = SparseGrid::new();
+        assert!(sut.is_empty());
+        assert!(SparseGrid::<u8>::default().is_empty());
+
+        sut.insert(Pos { x: 0, y: 0 }, 'a');
+        assert!(!sut.is_empty());
+
+        sut.remove(&Pos { x: 0, y: 0 });
+        assert!(sut.is_empty());
+    }
+
+    #[test]
+    fn test_area() {
+        let mut sut = SparseGrid::new();
+        assert_eq!(sut.area(), None);
+
+        sut.insert(Pos { x: 2, y: -1 }, 'a');
+        assert_eq!(sut.area(), Some(Area { max_x: 2, max_y: -1, min_x: 2, min_y: -1 }));
+
+        sut.insert(Pos { x: -3, y: 4 }, 'b');
+        sut.insert(Pos { x: 0, y: 0 }, 'c');
+        assert_eq!(sut.area(), Some(Area { max_x: 2, max_y: 4, min_x: -3, min_y: -1 }));
+    }
+
+    #[test]
+    fn test_load_factor() {
+        let mut sut = SparseGrid::new();
+        assert_eq!(sut.load_factor(), 0.0);
+
+        sut.insert(Pos { x: 0, y: 0 }, 'a');
+        assert_eq!(sut.load_factor(), 1.0);
+
+        sut.insert(Pos { x: 3, y: 1 }, 'b');
+        sut.insert(Pos { x: 1, y: 1 }, 'c');
+        assert_eq!(sut.load_factor(), 0.375);
+    }
+
+    #[test]
+    fn test_get() {
+        let mut sut = SparseGrid::new();
+        sut.cells.insert(Pos { x: -5, y: 3 }, 'a');
+        assert_eq!(sut.get(&Pos { x: -5, y: 3 }), Some(&'a'));
+        assert_eq!(sut.get(&Pos { x: 0, y: 0 }), None);
+    }
+
+    #[test]
+    fn test_insert() {
+        let mut sut = SparseGrid::new();
+        assert_eq!(sut.insert(Pos { x: 1, y: 1 }, 'a'), None);
+        assert_eq!(sut.insert(Pos { x: 1, y: 1 }, 'b'), Some('a'));
+        assert_eq!(sut.cells[&Pos { x: 1, y: 1 }], 'b');
+    }
+
+    #[test]
+    fn test_remove() {
+        let mut sut = SparseGrid::new();
+        sut.insert(Pos { x: 1, y: 1 }, 'a');
+        assert_eq!(sut.remove(&Pos { x: 1, y: 1 }), Some('a'));
+        assert_eq!(sut.remove(&Pos { x: 1, y: 1 }), None);
+    }
+
+    #[test]
+    fn test_neighbours() {
+        let mut sut = SparseGrid::new();
+        for pos in [Pos { x: 0, y: 0 }, Pos { x: 0, y: 1 }, Pos { x: 0, y: -1 }, Pos { x: 1, y: 0 }, Pos { x: -1, y: 0 }] {
+            sut.insert(pos, pos.x + pos.y);
+        }
+        sut.insert(Pos { x: 10, y: 10 }, 20);
+
+        let neighbours = sut.neighbours(&Pos { x: 0, y: 0 });
+        assert_eq!(neighbours.len(), 4);
+        assert!(neighbours.contains(&(Pos { x: 0, y: 1 }, &1)));
+        assert!(neighbours.contains(&(Pos { x: 0, y: -1 }, &-1)));
+        assert!(neighbours.contains(&(Pos { x: 1, y: 0 }, &1)));
+        assert!(neighbours.contains(&(Pos { x: -1, y: 0 }, &-1)));
+
+        let neighbours = sut.neighbours(&Pos { x: 1, y: 1 });
+        assert_eq!(neighbours.len(), 2);
+        assert!(neighbours.contains(&(Pos { x: 0, y: 1 }, &1)));
+        assert!(neighbours.contains(&(Pos { x: 1, y: 0 }, &1)));
+
+        assert!(sut.neighbours(&Pos { x: 10, y: 10 }).is_empty());
+    }
+}
@@ -3,6 +3,8 @@
 use std::fs::File;
 use std::io;
 use std::io::{read_to_string, BufRead};
+use std::str::FromStr;
+use crate::geo::direction::{Direction, ParseDirectionError};
 
 /// Returns all lines from a file.
 #[inline]
@@ -24,6 +26,34 @@ pub fn get_text(filename: &str) -> Result<String, io::Error> {
     read_to_string(file)
 }
 
+/// Returns the numbers in the first non-empty line of a file, separated by commas.
+/// Returns an [`io::ErrorKind::InvalidData`] error if any number fails to parse.
+pub fn read_number_line<T: FromStr>(filename: &str) -> io::Result<Vec<T>> {
+    for line in get_lines(filename)? {
+        let line = line?;
+        if line.trim().is_empty() {
+            continue;
+        }
+        return line
+            .split(',')
+            .map(|n| n.trim().parse().map_err(|_| io::Error::new(io::ErrorKind::InvalidData, format!("invalid number: {}", n))))
+            .collect();
+    }
+    Ok(Vec::new())
+}
+
+/// Parses a whitespace separated list of [`Direction`]s, returning the first error if any token is invalid.
+#[inline]
+pub fn parse_direction_sequence(line: &str) -> Result<Vec<Direction>, ParseDirectionError> {
+    line.split_whitespace().map(str::parse).collect()
+}
+
+/// Splits text at the first blank line. The second part is empty if there is no blank line.
+#[inline]
+pub fn split_at_blank_line(input: &str) -> (&str, &str) {
+    input.split_once("\n\n").unwrap_or((input, ""))
+}
+
 #[cfg(test)]
 mod test {
     use std::fs;
@@ -57,4 +87,52 @@ mod test {
         assert_eq!(sut, String::from("line 1\nline 2"));
         remove_file("test_get_text.txt").unwrap();
     }
+
+    #[test]
+    fn test_read_number_line() {
+        fs::write("test_read_number_line.txt", "1,2,3,4,5").unwrap();
+        let sut: Vec<i32> = read_number_line("test_read_number_line.txt").unwrap();
+        assert_eq!(sut, [1, 2, 3, 4, 5]);
+        remove_file("test_read_number_line.txt").unwrap();
+
+        fs::write("test_read_number_line_blank.txt", "\n  \n16, -1 ,2\n3,4\n").unwrap();
+        let sut: Vec<i64> = read_number_line("test_read_number_line_blank.txt").unwrap();
+        assert_eq!(sut, [16, -1, 2]);
+        remove_file("test_read_number_line_blank.txt").unwrap();
+
+        fs::write("test_read_number_line_invalid.txt", "1,x,3").unwrap();
+        let sut = read_number_line::<u8>("test_read_number_line_invalid.txt");
+        assert_eq!(sut.unwrap_err().kind(), io::ErrorKind::InvalidData);
+        remove_file("test_read_number_line_invalid.txt").unwrap();
+
+        assert!(read_number_line::<u8>("test_read_number_line_missing.txt").is_err());
+    }
+
+    #[test]
+    fn test_parse_direction_sequence() {
+        let sut = parse_direction_sequence("U R  D\tL ^ > v <").unwrap();
+        assert_eq!(sut, [
+            Direction::Up,
+            Direction::Right,
+            Direction::Down,
+            Direction::Left,
+            Direction::Up,
+            Direction::Right,
+            Direction::Down,
+            Direction::Left,
+        ]);
+
+        assert_eq!(parse_direction_sequence(""), Ok(vec![]));
+        assert_eq!(parse_direction_sequence("U X D"), Err(ParseDirectionError));
+    }
+
+    #[test]
+    fn test_split_at_blank_line() {
+        assert_eq!(split_at_blank_line("a\nb\n\nc\nd"), ("a\nb", "c\nd"));
+        assert_eq!(split_at_blank_line("a\n\nb\n\nc"), ("a", "b\n\nc"));
+        assert_eq!(split_at_blank_line("a\n\n\nb"), ("a", "\nb"));
+        assert_eq!(split_at_blank_line("a\nb"), ("a\nb", ""));
+        assert_eq!(split_at_blank_line("\n\nb"), ("", "b"));
+        assert_eq!(split_at_blank_line(""), ("", ""));
+    }
 }
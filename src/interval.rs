@@ -0,0 +1,129 @@
+#[cfg(not(feature = "std"))]
+use alloc::vec;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use num::Integer;
+
+/// An error returned when [`Interval<T>`]'s bounds are invalid.
+#[derive(Clone, Debug, Eq, PartialEq)]
+pub struct IntervalBoundaryError;
+
+/// An inclusive 1D interval. Intervals are ordered by start, then by end.
+#[derive(Clone, Copy, Debug, Eq, Hash, Ord, PartialEq, PartialOrd)]
+pub struct Interval<T> {
+    pub start: T,
+    pub end: T,
+}
+
+impl<T: PartialOrd> Interval<T> {
+    /// Returns a new [`Interval<T>`].
+    pub fn new(start: T, end: T) -> Result<Self, IntervalBoundaryError> {
+        if end < start {
+            return Err(IntervalBoundaryError);
+        }
+        Ok(Self { start, end })
+    }
+
+    /// Returns whether a value is inside this [`Interval<T>`].
+    #[inline]
+    pub fn contains(&self, value: &T) -> bool {
+        *value >= self.start && *value <= self.end
+    }
+}
+
+impl<T: Integer + Copy> Interval<T> {
+    /// Returns the parts of another [`Interval<T>`] which are not covered by this [`Interval<T>`].
+    pub fn complement(&self, within: &Self) -> Vec<Self> {
+        if self.end < within.start || self.start > within.end {
+            return vec![*within];
+        }
+
+        let mut result = Vec::with_capacity(2);
+        if self.start > within.start {
+            result.push(Self { start: within.start, end: self.start - T::one() });
+        }
+        if self.end < within.end {
+            result.push(Self { start: self.end + T::one(), end: within.end });
+        }
+        result
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use super::*;
+
+    #[test]
+    fn test_ord() {
+        let mut sut = vec![
+            Interval { start: 5, end: 8 },
+            Interval { start: 1, end: 4 },
+            Interval { start: 3, end: 9 },
+            Interval { start: 1, end: 2 },
+            Interval { start: -2, end: 0 },
+        ];
+        sut.sort();
+        assert_eq!(sut, vec![
+            Interval { start: -2, end: 0 },
+            Interval { start: 1, end: 2 },
+            Interval { start: 1, end: 4 },
+            Interval { start: 3, end: 9 },
+            Interval { start: 5, end: 8 },
+        ]);
+
+        let mut sut = vec![(Interval { start: 1, end: 3 }, 'a'), (Interval { start: 0, end: 5 }, 'b'), (Interval { start: 1, end: 3 }, 'c')];
+        sut.sort_by_key(|&(interval, _)| interval);
+        assert_eq!(sut, vec![(Interval { start: 0, end: 5 }, 'b'), (Interval { start: 1, end: 3 }, 'a'), (Interval { start: 1, end: 3 }, 'c')]);
+    }
+
+    #[test]
+    fn test_new() {
+        let sut = Interval::new(0, 10);
+        assert_eq!(sut.unwrap(), Interval { start: 0, end: 10 });
+
+        let sut = Interval::new(-3, -3);
+        assert_eq!(sut.unwrap(), Interval { start: -3, end: -3 });
+
+        let sut = Interval::new(1, 0);
+        assert!(sut.is_err());
+    }
+
+    #[test]
+    fn test_contains() {
+        let sut = Interval { start: -2, end: 5 };
+        assert!(sut.contains(&-2));
+        assert!(sut.contains(&0));
+        assert!(sut.contains(&5));
+        assert!(!sut.contains(&-3));
+        assert!(!sut.contains(&6));
+
+        let sut = Interval { start: 3, end: 3 };
+        assert!(sut.contains(&3));
+        assert!(!sut.contains(&2));
+    }
+
+    #[test]
+    fn test_complement() {
+        let within = Interval { start: 0, end: 10 };
+        let sut = Interval { start: 20, end: 30 };
+        assert_eq!(sut.complement(&within), vec![within]);
+
+        let sut = Interval { start: 5, end: 15 };
+        assert_eq!(sut.complement(&within), vec![Interval { start: 0, end: 4 }]);
+
+        let sut = Interval { start: -5, end: 3 };
+        assert_eq!(sut.complement(&within), vec![Interval { start: 4, end: 10 }]);
+
+        let sut = Interval { start: 3, end: 6 };
+        assert_eq!(sut.complement(&within), vec![Interval { start: 0, end: 2 }, Interval { start: 7, end: 10 }]);
+
+        let sut = Interval { start: -1, end: 11 };
+        assert!(sut.complement(&within).is_empty());
+
+        let sut = Interval { start: 0, end: 10 };
+        assert!(sut.complement(&within).is_empty());
+
+        let sut = Interval { start: 10, end: 12 };
+        assert_eq!(sut.complement(&within), vec![Interval { start: 0, end: 9 }]);
+    }
+}
@@ -5,4 +5,6 @@ extern crate alloc;
 
 pub mod geo;
 pub mod input;
+pub mod interval;
 pub mod math;
+pub mod search;
@@ -1,4 +1,8 @@
-use num::Integer;
+#[cfg(not(feature = "std"))]
+use alloc::vec::Vec;
+use core::ops::Sub;
+use num::{CheckedMul, Integer, Signed};
+use num::integer::Roots;
 use num::integer::{gcd, lcm};
 
 /// Computes [GCD](https://en.wikipedia.org/wiki/Greatest_common_divisor) on a range of numbers.
@@ -13,12 +17,185 @@ pub fn lcm_iter<T: Integer>(nums: impl IntoIterator<Item = T>) -> T {
     nums.into_iter().fold(T::one(), move |acc, x| lcm(acc, x))
 }
 
+/// Computes [GCD](https://en.wikipedia.org/wiki/Greatest_common_divisor) on a range of numbers, along with
+/// [Bézout coefficients](https://en.wikipedia.org/wiki/B%C3%A9zout%27s_identity) whose linear combination with the numbers equals the GCD.
+pub fn gcd_extended_iter<T: Integer + Signed + Clone>(nums: impl IntoIterator<Item = T>) -> (T, Vec<T>) {
+    let mut coefficients: Vec<T> = Vec::new();
+    let mut result = T::zero();
+    for num in nums {
+        let extended = result.extended_gcd(&num);
+        let (gcd, a, b) = if extended.gcd.is_negative() {
+            (-extended.gcd, -extended.x, -extended.y)
+        } else {
+            (extended.gcd, extended.x, extended.y)
+        };
+        coefficients.iter_mut().for_each(|c| *c = c.clone() * a.clone());
+        coefficients.push(b);
+        result = gcd;
+    }
+    (result, coefficients)
+}
+
+/// Computes the sum of squares on a range of numbers.
+#[inline]
+pub fn sum_of_squares<T: Integer + Copy>(nums: impl IntoIterator<Item = T>) -> T {
+    nums.into_iter().fold(T::zero(), move |acc, x| acc + x * x)
+}
+
+/// Computes the sum of squares from `1` to `n` using the closed formula `n(n + 1)(2n + 1) / 6`.
+#[inline]
+pub fn sum_of_first_n_squares<T: Integer + Copy>(n: T) -> T {
+    let (two, three) = (T::one() + T::one(), T::one() + T::one() + T::one());
+    n * (n + T::one()) * (two * n + T::one()) / (two * three)
+}
+
 /// Returns the number of digits in a number.
 #[inline]
 pub fn digits(num: i64) -> u32 {
     num.abs().checked_ilog10().unwrap_or(0) + 1
 }
 
+/// Returns an iterator over the digits of a non-negative number in a base, from the most significant digit.
+/// The iterator is empty if the base is less than 2.
+pub fn digits_base_iter<T: Integer + Copy>(n: T, base: T) -> impl Iterator<Item = T> {
+    let mut power = if base > T::one() { T::one() } else { T::zero() };
+    while !power.is_zero() && power <= n / base {
+        power = power * base;
+    }
+    core::iter::successors(Some(power).filter(|p| !p.is_zero()), move |&p| Some(p / base).filter(|p| !p.is_zero())).map(move |p| n / p % base)
+}
+
+/// Returns the distinct integer solutions of `ax² + bx + c = 0` in ascending order.
+/// Solutions which are not integers are skipped, so the second value is only set when both solutions are integers.
+/// Returns `(None, None)` if the discriminant overflows.
+pub fn solve_quadratic_integer(a: i64, b: i64, c: i64) -> (Option<i64>, Option<i64>) {
+    let (a, b, c) = (a as i128, b as i128, c as i128);
+    let exact_div = |n: i128, d: i128| if d != 0 && n % d == 0 { i64::try_from(n / d).ok() } else { None };
+    if a == 0 {
+        return (exact_div(-c, b), None);
+    }
+
+    let Some(discriminant) = (4 * a).checked_mul(c).and_then(|ac| (b * b).checked_sub(ac)) else {
+        return (None, None);
+    };
+    let root = if discriminant >= 0 { discriminant.sqrt() } else { -1 };
+    if root < 0 || root * root != discriminant {
+        return (None, None);
+    }
+
+    let (x1, x2) = (exact_div(-b - root, 2 * a), exact_div(-b + root, 2 * a));
+    match (x1, x2) {
+        (Some(x1), Some(x2)) if x1 == x2 => (Some(x1), None),
+        (Some(x1), Some(x2)) => (Some(x1.min(x2)), Some(x1.max(x2))),
+        (x1, x2) => (x1.or(x2), None),
+    }
+}
+
+/// Computes the floor of the logarithm of a number in a base, or [`None`] if the number is less than 1 or the base is less than 2.
+pub fn integer_log<T: Integer + Copy>(n: T, base: T) -> Option<T> {
+    if n < T::one() || base <= T::one() {
+        return None;
+    }
+
+    let (mut n, mut result) = (n, T::zero());
+    while n >= base {
+        n = n / base;
+        result = result + T::one();
+    }
+    Some(result)
+}
+
+/// Computes the [factorial](https://en.wikipedia.org/wiki/Factorial) of a number, or [`None`] if negative or overflow.
+pub fn factorial<T: Integer + CheckedMul + Copy>(n: T) -> Option<T> {
+    if n < T::zero() {
+        return None;
+    }
+
+    let mut result = T::one();
+    let mut i = T::one();
+    while i < n {
+        i = i + T::one();
+        result = result.checked_mul(&i)?;
+    }
+    Some(result)
+}
+
+/// Computes `(a + b) mod m` without intermediate overflow. The result is always in `[0, m)` for positive `m`.
+#[inline]
+pub fn modular_add<T: Integer + Copy>(a: T, b: T, m: T) -> T {
+    let (a, b) = (a.mod_floor(&m), b.mod_floor(&m));
+    if a >= m - b { a - (m - b) } else { a + b }
+}
+
+/// Computes `(a * b) mod m` without intermediate overflow. The result is always in `[0, m)` for positive `m`.
+pub fn modular_mul<T: Integer + Copy>(a: T, b: T, m: T) -> T {
+    let two = T::one() + T::one();
+    let (mut a, mut b) = (a.mod_floor(&m), b.mod_floor(&m));
+    let mut result = T::zero();
+    while !b.is_zero() {
+        if b.is_odd() {
+            result = modular_add(result, a, m);
+        }
+        a = modular_add(a, a, m);
+        b = b / two;
+    }
+    result
+}
+
+/// Returns the running minimums of a slice, where `result[i]` is the minimum of `values[0..=i]`.
+pub fn prefix_min<T: Ord + Clone>(values: &[T]) -> Vec<T> {
+    let mut result: Vec<T> = Vec::with_capacity(values.len());
+    for v in values {
+        let next = match result.last() {
+            Some(last) if last < v => last.clone(),
+            _ => v.clone(),
+        };
+        result.push(next);
+    }
+    result
+}
+
+/// Returns the running maximums of a slice, where `result[i]` is the maximum of `values[0..=i]`.
+pub fn prefix_max<T: Ord + Clone>(values: &[T]) -> Vec<T> {
+    let mut result: Vec<T> = Vec::with_capacity(values.len());
+    for v in values {
+        let next = match result.last() {
+            Some(last) if last > v => last.clone(),
+            _ => v.clone(),
+        };
+        result.push(next);
+    }
+    result
+}
+
+/// Evaluates the polynomial `sum(coefficients[i] * base^i)` using [Horner's method](https://en.wikipedia.org/wiki/Horner%27s_method).
+#[inline]
+pub fn weighted_sum<T: Integer + Copy>(coefficients: &[T], base: T) -> T {
+    coefficients.iter().rev().fold(T::zero(), |acc, &c| acc * base + c)
+}
+
+/// Returns the differences between consecutive values of a slice, where `result[i]` is `values[i + 1] - values[i]`.
+#[inline]
+pub fn iter_differences<T: Sub<Output = T> + Copy>(values: &[T]) -> Vec<T> {
+    values.windows(2).map(|w| w[1] - w[0]).collect()
+}
+
+/// Extends a polynomial sequence by one term after its last value using [finite differences](https://en.wikipedia.org/wiki/Finite_difference).
+pub fn extrapolate_forward<T: Integer + Copy>(values: &[T]) -> T {
+    match values.last() {
+        Some(&last) if !values.iter().all(T::is_zero) => last + extrapolate_forward(&iter_differences(values)),
+        _ => T::zero(),
+    }
+}
+
+/// Extends a polynomial sequence by one term before its first value using [finite differences](https://en.wikipedia.org/wiki/Finite_difference).
+pub fn extrapolate_backward<T: Integer + Copy>(values: &[T]) -> T {
+    match values.first() {
+        Some(&first) if !values.iter().all(T::is_zero) => first - extrapolate_backward(&iter_differences(values)),
+        _ => T::zero(),
+    }
+}
+
 #[cfg(test)]
 mod test {
     use super::*;
@@ -47,6 +224,45 @@ mod test {
         assert_eq!(lcm_iter(sut), 720);
     }
 
+    #[test]
+    fn test_gcd_extended_iter() {
+        let nums = [48, 180, 240, 60];
+        let (gcd, coefficients) = gcd_extended_iter(nums);
+        assert_eq!(gcd, 12);
+        assert_eq!(coefficients.len(), 4);
+        assert_eq!(nums.iter().zip(&coefficients).map(|(n, c)| n * c).sum::<i32>(), gcd);
+
+        let nums = [6, 10, 15];
+        let (gcd, coefficients) = gcd_extended_iter(nums);
+        assert_eq!(gcd, 1);
+        assert_eq!(nums.iter().zip(&coefficients).map(|(n, c)| n * c).sum::<i32>(), gcd);
+
+        let nums = [-8i64, 12, -20];
+        let (gcd, coefficients) = gcd_extended_iter(nums);
+        assert_eq!(gcd, 4);
+        assert_eq!(nums.iter().zip(&coefficients).map(|(n, c)| n * c).sum::<i64>(), gcd);
+
+        assert_eq!(gcd_extended_iter([-7]), (7, vec![-1]));
+        assert_eq!(gcd_extended_iter::<i32>([]), (0, vec![]));
+    }
+
+    #[test]
+    fn test_sum_of_squares() {
+        assert_eq!(sum_of_squares([3, 4]), 25);
+        assert_eq!(sum_of_squares([-1, 2, -3]), 14);
+        assert_eq!(sum_of_squares::<i32>([]), 0);
+    }
+
+    #[test]
+    fn test_sum_of_first_n_squares() {
+        assert_eq!(sum_of_first_n_squares(0), 0);
+        assert_eq!(sum_of_first_n_squares(1), 1);
+        assert_eq!(sum_of_first_n_squares(10), 385);
+        for n in 0..100i64 {
+            assert_eq!(sum_of_first_n_squares(n), sum_of_squares(1..=n));
+        }
+    }
+
     #[test]
     fn test_digits() {
         assert_eq!(digits(0), 1);
@@ -54,4 +270,147 @@ mod test {
         assert_eq!(digits(100), 3);
         assert_eq!(digits(789456123), 9);
     }
+
+    #[test]
+    fn test_digits_base_iter() {
+        for n in [0, 7, 10, 100, 4096, 789456123] {
+            let sut: Vec<_> = digits_base_iter(n, 10).collect();
+            assert_eq!(sut.len() as u32, digits(n));
+            assert_eq!(sut.iter().fold(0, |acc, d| acc * 10 + d), n);
+        }
+        assert_eq!(digits_base_iter(1234, 10).collect::<Vec<_>>(), vec![1, 2, 3, 4]);
+        assert_eq!(digits_base_iter(10u8, 2).collect::<Vec<_>>(), vec![1, 0, 1, 0]);
+        assert_eq!(digits_base_iter(255u8, 16).collect::<Vec<_>>(), vec![15, 15]);
+        assert_eq!(digits_base_iter(u64::MAX, 10).next(), Some(1));
+        assert_eq!(digits_base_iter(0, 2).collect::<Vec<_>>(), vec![0]);
+        assert_eq!(digits_base_iter(1234, 1).count(), 0);
+        assert_eq!(digits_base_iter(1234, 0).count(), 0);
+        assert_eq!(digits_base_iter(1234, -10).count(), 0);
+        assert_eq!(digits_base_iter(0u8, 1).count(), 0);
+    }
+
+    #[test]
+    fn test_solve_quadratic_integer() {
+        assert_eq!(solve_quadratic_integer(1, -5, 6), (Some(2), Some(3)));
+        assert_eq!(solve_quadratic_integer(-1, 5, -6), (Some(2), Some(3)));
+        assert_eq!(solve_quadratic_integer(1, 0, -16), (Some(-4), Some(4)));
+        assert_eq!(solve_quadratic_integer(1, -4, 4), (Some(2), None));
+        assert_eq!(solve_quadratic_integer(2, -3, 1), (Some(1), None));
+        assert_eq!(solve_quadratic_integer(1, 0, -2), (None, None));
+        assert_eq!(solve_quadratic_integer(1, 0, 1), (None, None));
+        assert_eq!(solve_quadratic_integer(0, 2, -6), (Some(3), None));
+        assert_eq!(solve_quadratic_integer(0, 0, 1), (None, None));
+        assert_eq!(solve_quadratic_integer(1, -3_000_000_000, 2_000_000_000_000_000_000), (Some(1_000_000_000), Some(2_000_000_000)));
+        assert_eq!(solve_quadratic_integer(i64::MIN, 0, i64::MIN), (None, None));
+        assert_eq!(solve_quadratic_integer(i64::MAX, i64::MIN, i64::MAX), (None, None));
+        assert_eq!(solve_quadratic_integer(i64::MIN, i64::MIN, 0), (Some(-1), Some(0)));
+    }
+
+    #[test]
+    fn test_integer_log() {
+        assert_eq!(integer_log(8, 2), Some(3));
+        assert_eq!(integer_log(9, 2), Some(3));
+        assert_eq!(integer_log(7, 2), Some(2));
+        assert_eq!(integer_log(1, 10), Some(0));
+        assert_eq!(integer_log(1000, 10), Some(3));
+        assert_eq!(integer_log(u64::MAX, 2), Some(63));
+        assert_eq!(integer_log(0, 2), None);
+        assert_eq!(integer_log(-8, 2), None);
+        assert_eq!(integer_log(8, 1), None);
+        assert_eq!(integer_log(8, 0), None);
+    }
+
+    #[test]
+    fn test_factorial() {
+        assert_eq!(factorial(0), Some(1));
+        assert_eq!(factorial(1), Some(1));
+        assert_eq!(factorial(5), Some(120));
+        assert_eq!(factorial(12), Some(479001600));
+        assert_eq!(factorial(13), None);
+        assert_eq!(factorial(20i64), Some(2432902008176640000));
+        assert_eq!(factorial(21i64), None);
+        assert_eq!(factorial(-1), None);
+    }
+
+    #[test]
+    fn test_modular_add() {
+        assert_eq!(modular_add(3, 4, 5), 2);
+        assert_eq!(modular_add(-3, 1, 5), 3);
+        assert_eq!(modular_add(i32::MAX, i32::MAX, 1_000_000_007), ((i32::MAX as i64 * 2) % 1_000_000_007) as i32);
+        assert_eq!(modular_add(u8::MAX, u8::MAX, 200), 110);
+    }
+
+    #[test]
+    fn test_modular_mul() {
+        assert_eq!(modular_mul(3, 4, 5), 2);
+        assert_eq!(modular_mul(-3, 4, 5), 3);
+        assert_eq!(modular_mul(0, 4, 5), 0);
+        assert_eq!(modular_mul(2_000_000_000, 2_000_000_000, 1_000_000_007), ((2_000_000_000i64 * 2_000_000_000) % 1_000_000_007) as i32);
+        assert_eq!(modular_mul(i32::MAX, i32::MAX - 1, i32::MAX - 2), ((i32::MAX as i64 * (i32::MAX as i64 - 1)) % (i32::MAX as i64 - 2)) as i32);
+        assert_eq!(modular_mul(u64::MAX, u64::MAX, 1_000_000_007), ((u64::MAX as u128 * u64::MAX as u128) % 1_000_000_007) as u64);
+    }
+
+    #[test]
+    fn test_prefix_min() {
+        assert_eq!(prefix_min(&[1, 2, 3, 4]), vec![1, 1, 1, 1]);
+        assert_eq!(prefix_min(&[4, 3, 2, 1]), vec![4, 3, 2, 1]);
+        assert_eq!(prefix_min(&[3, 5, 1, 4, 0, 2]), vec![3, 3, 1, 1, 0, 0]);
+        assert!(prefix_min::<i32>(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_prefix_max() {
+        assert_eq!(prefix_max(&[1, 2, 3, 4]), vec![1, 2, 3, 4]);
+        assert_eq!(prefix_max(&[4, 3, 2, 1]), vec![4, 4, 4, 4]);
+        assert_eq!(prefix_max(&[3, 5, 1, 4, 6, 2]), vec![3, 5, 5, 5, 6, 6]);
+        assert!(prefix_max::<i32>(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_weighted_sum() {
+        let coefficients = [1, -2, 0, 3];
+        assert_eq!(weighted_sum(&coefficients, 0), 1);
+        assert_eq!(weighted_sum(&coefficients, 1), 2);
+        assert_eq!(weighted_sum(&coefficients, 2), 21);
+        assert_eq!(weighted_sum(&coefficients, -3), -74);
+        assert_eq!(weighted_sum::<i32>(&[], 10), 0);
+
+        for n in [0u64, 7, 1234, 789456123] {
+            let mut digits: Vec<_> = digits_base_iter(n, 10).collect();
+            digits.reverse();
+            assert_eq!(weighted_sum(&digits, 10), n);
+        }
+        assert_eq!(weighted_sum(&[0, 1, 0, 1], 2), 10);
+    }
+
+    #[test]
+    fn test_iter_differences() {
+        assert_eq!(iter_differences(&[1, 3, 6, 10]), vec![2, 3, 4]);
+        assert_eq!(iter_differences(&[2, 3, 4]), vec![1, 1]);
+        assert_eq!(iter_differences(&[1, 1]), vec![0]);
+        assert_eq!(iter_differences(&[7, 7, 7, 7]), vec![0, 0, 0]);
+        assert_eq!(iter_differences(&[5, -2]), vec![-7]);
+        assert!(iter_differences(&[5]).is_empty());
+        assert!(iter_differences::<i32>(&[]).is_empty());
+    }
+
+    #[test]
+    fn test_extrapolate_forward() {
+        assert_eq!(extrapolate_forward(&[0, 3, 6, 9, 12, 15]), 18);
+        assert_eq!(extrapolate_forward(&[1, 3, 6, 10, 15, 21]), 28);
+        assert_eq!(extrapolate_forward(&[10, 13, 16, 21, 30, 45]), 68);
+        assert_eq!(extrapolate_forward(&[4, 4, 4]), 4);
+        assert_eq!(extrapolate_forward(&[0, 0]), 0);
+        assert_eq!(extrapolate_forward::<i32>(&[]), 0);
+    }
+
+    #[test]
+    fn test_extrapolate_backward() {
+        assert_eq!(extrapolate_backward(&[0, 3, 6, 9, 12, 15]), -3);
+        assert_eq!(extrapolate_backward(&[1, 3, 6, 10, 15, 21]), 0);
+        assert_eq!(extrapolate_backward(&[10, 13, 16, 21, 30, 45]), 5);
+        assert_eq!(extrapolate_backward(&[4, 4, 4]), 4);
+        assert_eq!(extrapolate_backward(&[0, 0]), 0);
+        assert_eq!(extrapolate_backward::<i32>(&[]), 0);
+    }
 }
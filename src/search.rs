@@ -0,0 +1,371 @@
+#![cfg(feature = "std")]
+
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap, HashSet, VecDeque};
+use std::hash::Hash;
+use std::ops::Add;
+use num::Zero;
+use crate::geo::direction::{Direction, DirectionalPos};
+use crate::geo::grid::Grid;
+use crate::geo::pos::PosIdx;
+
+/// Counts the distinct minimum cost paths from `start` to `goal` using [A*](https://en.wikipedia.org/wiki/A*_search_algorithm).
+/// Edge costs must be positive and the heuristic must be consistent. Returns `0` if `goal` is unreachable.
+pub fn a_star_path_count<S, C, FN, IN, H>(start: S, goal: &S, neighbours: FN, heuristic: H) -> usize
+where
+    S: Hash + Eq + Clone,
+    C: Ord + Copy + Zero + Add<Output = C>,
+    FN: Fn(&S) -> IN,
+    IN: IntoIterator<Item = (S, C)>,
+    H: Fn(&S) -> C,
+{
+    let mut ids = HashMap::from([(start.clone(), 0)]);
+    let (mut states, mut costs, mut counts, mut closed) = (vec![start.clone()], vec![C::zero()], vec![1], vec![false]);
+    let mut queue = BinaryHeap::from([Reverse((heuristic(&start), C::zero(), 0))]);
+    while let Some(Reverse((_, cost, id))) = queue.pop() {
+        if closed[id] || cost > costs[id] {
+            continue;
+        }
+        closed[id] = true;
+        if states[id] == *goal {
+            return counts[id];
+        }
+
+        for (next, step) in neighbours(&states[id]) {
+            let next_cost = cost + step;
+            let priority = next_cost + heuristic(&next);
+            match ids.get(&next) {
+                None => {
+                    ids.insert(next.clone(), states.len());
+                    queue.push(Reverse((priority, next_cost, states.len())));
+                    states.push(next);
+                    costs.push(next_cost);
+                    counts.push(counts[id]);
+                    closed.push(false);
+                }
+                Some(&next_id) if !closed[next_id] && next_cost < costs[next_id] => {
+                    costs[next_id] = next_cost;
+                    counts[next_id] = counts[id];
+                    queue.push(Reverse((priority, next_cost, next_id)));
+                }
+                Some(&next_id) if !closed[next_id] && next_cost == costs[next_id] => counts[next_id] += counts[id],
+                _ => {}
+            }
+        }
+    }
+    0
+}
+
+/// Sorts nodes [topologically](https://en.wikipedia.org/wiki/Topological_sorting) using Kahn's algorithm.
+/// Nodes with no remaining dependencies are processed in ascending order, so the result is deterministic.
+/// Returns [`None`] if the graph contains a cycle.
+pub fn topological_sort_kahn_stable<T: Ord + Hash + Clone + Eq>(
+    nodes: impl IntoIterator<Item = T>,
+    edges: impl IntoIterator<Item = (T, T)>
+) -> Option<Vec<T>> {
+    let mut in_degrees: HashMap<T, usize> = nodes.into_iter().map(|n| (n, 0)).collect();
+    let mut adjacency: HashMap<T, Vec<T>> = HashMap::new();
+    for (from, to) in edges {
+        in_degrees.entry(from.clone()).or_insert(0);
+        *in_degrees.entry(to.clone()).or_insert(0) += 1;
+        adjacency.entry(from).or_default().push(to);
+    }
+
+    let mut queue: BinaryHeap<_> = in_degrees
+        .iter()
+        .filter(|(_, &degree)| degree == 0)
+        .map(|(node, _)| Reverse(node.clone()))
+        .collect();
+    let mut result = Vec::with_capacity(in_degrees.len());
+    while let Some(Reverse(node)) = queue.pop() {
+        for next in adjacency.get(&node).into_iter().flatten() {
+            let degree = in_degrees.get_mut(next)?;
+            *degree -= 1;
+            if *degree == 0 {
+                queue.push(Reverse(next.clone()));
+            }
+        }
+        result.push(node);
+    }
+
+    if result.len() == in_degrees.len() { Some(result) } else { None }
+}
+
+/// Runs a [breadth-first search](https://en.wikipedia.org/wiki/Breadth-first_search) and returns the depth of the first state reaching a goal,
+/// or [`None`] if no reachable state satisfies the goal.
+pub fn bfs_until<S, FN, IN, F>(start: S, neighbours: FN, goal: F) -> Option<usize>
+where
+    S: Hash + Eq + Clone,
+    FN: Fn(&S) -> IN,
+    IN: IntoIterator<Item = S>,
+    F: Fn(&S) -> bool,
+{
+    let mut visited = HashSet::from([start.clone()]);
+    let mut queue = VecDeque::from([(start, 0)]);
+    while let Some((state, depth)) = queue.pop_front() {
+        if goal(&state) {
+            return Some(depth);
+        }
+        for next in neighbours(&state) {
+            if visited.insert(next.clone()) {
+                queue.push_back((next, depth + 1));
+            }
+        }
+    }
+    None
+}
+
+/// Returns the edges of a [minimum spanning tree](https://en.wikipedia.org/wiki/Minimum_spanning_tree) using Kruskal's algorithm.
+/// Nodes are numbered from `0` to `nodes - 1`. A spanning forest is returned if the graph is disconnected.
+pub fn minimum_spanning_tree<W: Ord + Copy>(nodes: usize, edges: &[(usize, usize, W)]) -> Vec<(usize, usize, W)> {
+    let mut sorted = edges.to_vec();
+    sorted.sort_by_key(|&(_, _, weight)| weight);
+
+    let mut parents: Vec<_> = (0..nodes).collect();
+    let mut result = Vec::with_capacity(nodes.saturating_sub(1));
+    for (from, to, weight) in sorted {
+        let (from_root, to_root) = (find_root(&mut parents, from), find_root(&mut parents, to));
+        if from_root != to_root {
+            parents[from_root] = to_root;
+            result.push((from, to, weight));
+        }
+    }
+    result
+}
+
+/// Returns the minimum cost from `start` to `goal` on a [`Grid<T>`] using [Dijkstra's algorithm](https://en.wikipedia.org/wiki/Dijkstra%27s_algorithm),
+/// where entering a cell costs `step_cost` and every change of direction costs `turn_cost`. The initial direction is free.
+/// Cells where `step_cost` returns [`None`] cannot be entered. Returns [`None`] if `goal` is unreachable.
+pub fn dijkstra_with_turn_cost<T>(
+    grid: &Grid<T>,
+    start: PosIdx,
+    goal: PosIdx,
+    step_cost: impl Fn(PosIdx, &T) -> Option<usize>,
+    turn_cost: usize
+) -> Option<usize> {
+    let starts = Direction::cross().map(|dir| DirectionalPos::new(start, dir));
+    let mut costs: HashMap<_, _> = starts.iter().map(|&state| (state, 0)).collect();
+    let mut queue: BinaryHeap<_> = starts.into_iter().map(|state| Reverse((0, state))).collect();
+    while let Some(Reverse((cost, state))) = queue.pop() {
+        if state.pos == goal {
+            return Some(cost);
+        }
+        if cost > costs[&state] {
+            continue;
+        }
+
+        for dir in [state.direction, state.direction.left(), state.direction.right()] {
+            let Some(pos) = state.pos.checked_dest(1, dir).filter(|p| grid.has(p)) else {
+                continue;
+            };
+            let Some(step) = step_cost(pos, &grid[pos]) else {
+                continue;
+            };
+
+            let next = DirectionalPos::new(pos, dir);
+            let next_cost = cost + step + if dir == state.direction { 0 } else { turn_cost };
+            if costs.get(&next).is_none_or(|&c| next_cost < c) {
+                costs.insert(next, next_cost);
+                queue.push(Reverse((next_cost, next)));
+            }
+        }
+    }
+    None
+}
+
+/// Returns the root of a node in a union-find forest, compressing the path along the way.
+fn find_root(parents: &mut [usize], node: usize) -> usize {
+    let mut root = node;
+    while parents[root] != root {
+        root = parents[root];
+    }
+
+    let mut current = node;
+    while parents[current] != root {
+        let next = parents[current];
+        parents[current] = root;
+        current = next;
+    }
+    root
+}
+
+/// Returns a [maximum matching](https://en.wikipedia.org/wiki/Maximum_cardinality_matching) of a bipartite graph using Kuhn's algorithm.
+/// Left nodes are numbered from `0` to `n - 1` and right nodes from `0` to `m - 1`. Each edge connects a left node to a right node.
+/// `result[i]` is the right node matched to left node `i`, or [`None`] if it is unmatched.
+pub fn bipartite_matching(n: usize, m: usize, edges: &[(usize, usize)]) -> Vec<Option<usize>> {
+    let mut adjacency = vec![Vec::new(); n];
+    for &(left, right) in edges {
+        adjacency[left].push(right);
+    }
+
+    let mut matched_left = vec![None; m];
+    for left in 0..n {
+        augment(&adjacency, left, &mut vec![false; m], &mut matched_left);
+    }
+
+    let mut result = vec![None; n];
+    for (right, left) in matched_left.into_iter().enumerate() {
+        if let Some(left) = left {
+            result[left] = Some(right);
+        }
+    }
+    result
+}
+
+/// Tries to find an augmenting path from a left node, updating the matched left node of each right node along the way.
+fn augment(adjacency: &[Vec<usize>], left: usize, visited: &mut [bool], matched_left: &mut [Option<usize>]) -> bool {
+    for &right in &adjacency[left] {
+        if visited[right] {
+            continue;
+        }
+        visited[right] = true;
+        if matched_left[right].is_none_or(|other| augment(adjacency, other, visited, matched_left)) {
+            matched_left[right] = Some(left);
+            return true;
+        }
+    }
+    false
+}
+
+#[cfg(test)]
+mod test {
+    use crate::geo::grid::CharGrid;
+    use super::*;
+
+    fn grid_neighbours(grid: &CharGrid) -> impl Fn(&PosIdx) -> Vec<(PosIdx, usize)> + '_ {
+        move |pos| {
+            Direction::cross()
+                .into_iter()
+                .filter_map(|dir| pos.checked_dest(1, dir))
+                .filter(|p| grid.has(p) && grid[*p] != b'#')
+                .map(|p| (p, 1))
+                .collect()
+        }
+    }
+
+    #[test]
+    fn test_a_star_path_count() {
+        let grid: CharGrid = "..\n..".parse().unwrap();
+        let goal = PosIdx { x: 1, y: 1 };
+        let sut = a_star_path_count(PosIdx { x: 0, y: 0 }, &goal, grid_neighbours(&grid), |p| p.manhattan_unsigned(&goal));
+        assert_eq!(sut, 2);
+
+        let grid: CharGrid = "...\n...\n...".parse().unwrap();
+        let goal = PosIdx { x: 2, y: 2 };
+        let sut = a_star_path_count(PosIdx { x: 0, y: 0 }, &goal, grid_neighbours(&grid), |p| p.manhattan_unsigned(&goal));
+        assert_eq!(sut, 6);
+
+        let sut = a_star_path_count(PosIdx { x: 0, y: 0 }, &goal, grid_neighbours(&grid), |_| 0);
+        assert_eq!(sut, 6);
+
+        let grid: CharGrid = "...\n.#.\n...".parse().unwrap();
+        let sut = a_star_path_count(PosIdx { x: 0, y: 0 }, &goal, grid_neighbours(&grid), |p| p.manhattan_unsigned(&goal));
+        assert_eq!(sut, 2);
+
+        let grid: CharGrid = ".#.\n#..\n...".parse().unwrap();
+        let sut = a_star_path_count(PosIdx { x: 0, y: 0 }, &goal, grid_neighbours(&grid), |p| p.manhattan_unsigned(&goal));
+        assert_eq!(sut, 0);
+
+        let sut = a_star_path_count(PosIdx { x: 1, y: 1 }, &PosIdx { x: 1, y: 1 }, grid_neighbours(&grid), |_| 0);
+        assert_eq!(sut, 1);
+    }
+
+    #[test]
+    fn test_topological_sort_kahn_stable() {
+        let edges = [('C', 'A'), ('C', 'F'), ('A', 'B'), ('A', 'D'), ('B', 'E'), ('D', 'E'), ('F', 'E')];
+        let sut = topological_sort_kahn_stable([], edges).unwrap();
+        assert_eq!(sut.into_iter().collect::<String>(), "CABDFE");
+
+        let sut = topological_sort_kahn_stable(['Z', 'B', 'A'], []).unwrap();
+        assert_eq!(sut, vec!['A', 'B', 'Z']);
+
+        let sut = topological_sort_kahn_stable(['X'], [('B', 'C'), ('A', 'C')]).unwrap();
+        assert_eq!(sut, vec!['A', 'B', 'C', 'X']);
+
+        let sut = topological_sort_kahn_stable([], [(1, 2), (2, 3), (3, 1)]);
+        assert!(sut.is_none());
+    }
+
+    #[test]
+    fn test_bfs_until() {
+        let grid: CharGrid = "..#....\n.##.##.\n....#..\n##.##.#\n.......".parse().unwrap();
+        let cross_neighbours = |p: &PosIdx| grid_neighbours(&grid)(p).into_iter().map(|(p, _)| p).collect::<Vec<_>>();
+
+        let mut depths = HashMap::from([(PosIdx { x: 0, y: 0 }, 0)]);
+        let mut queue = VecDeque::from([PosIdx { x: 0, y: 0 }]);
+        while let Some(pos) = queue.pop_front() {
+            for next in cross_neighbours(&pos) {
+                if !depths.contains_key(&next) {
+                    depths.insert(next, depths[&pos] + 1);
+                    queue.push_back(next);
+                }
+            }
+        }
+
+        for goal in [PosIdx { x: 0, y: 0 }, PosIdx { x: 6, y: 0 }, PosIdx { x: 3, y: 1 }, PosIdx { x: 6, y: 4 }] {
+            let sut = bfs_until(PosIdx { x: 0, y: 0 }, cross_neighbours, |p| *p == goal);
+            assert_eq!(sut, depths.get(&goal).copied());
+        }
+        assert_eq!(bfs_until(PosIdx { x: 0, y: 0 }, cross_neighbours, |p| *p == PosIdx { x: 6, y: 4 }), Some(10));
+        assert_eq!(bfs_until(PosIdx { x: 0, y: 0 }, cross_neighbours, |p| p.y == 4), Some(6));
+        assert_eq!(bfs_until(PosIdx { x: 0, y: 0 }, cross_neighbours, |p| grid[*p] == b'#'), None);
+    }
+
+    #[test]
+    fn test_dijkstra_with_turn_cost() {
+        let grid: CharGrid = "19991\n11111".parse().unwrap();
+        let step_cost = |_, &c: &u8| if c == b'#' { None } else { Some((c - b'0') as usize) };
+        let (start, goal) = (PosIdx { x: 0, y: 0 }, PosIdx { x: 4, y: 0 });
+        assert_eq!(dijkstra_with_turn_cost(&grid, start, goal, step_cost, 0), Some(6));
+        assert_eq!(dijkstra_with_turn_cost(&grid, start, goal, step_cost, 10), Some(26));
+        assert_eq!(dijkstra_with_turn_cost(&grid, start, goal, step_cost, 100), Some(28));
+        assert_eq!(dijkstra_with_turn_cost(&grid, start, start, step_cost, 100), Some(0));
+
+        let grid: CharGrid = "111\n###\n111".parse().unwrap();
+        let sut = dijkstra_with_turn_cost(&grid, PosIdx { x: 0, y: 0 }, PosIdx { x: 2, y: 2 }, step_cost, 1);
+        assert_eq!(sut, None);
+
+        let grid: CharGrid = "111\n1#1\n111".parse().unwrap();
+        let sut = dijkstra_with_turn_cost(&grid, PosIdx { x: 0, y: 0 }, PosIdx { x: 2, y: 2 }, step_cost, 5);
+        assert_eq!(sut, Some(9));
+    }
+
+    #[test]
+    fn test_minimum_spanning_tree() {
+        let edges = [(0, 1, 7), (0, 3, 5), (1, 2, 8), (1, 3, 9), (1, 4, 7), (2, 4, 5), (3, 4, 15), (3, 5, 6), (4, 5, 8), (4, 6, 9), (5, 6, 11)];
+        let sut = minimum_spanning_tree(7, &edges);
+        assert_eq!(sut.len(), 6);
+        assert_eq!(sut.iter().map(|&(_, _, w)| w).sum::<i32>(), 39);
+
+        let sut = minimum_spanning_tree(4, &[(0, 1, 1), (1, 2, 1), (0, 2, 1), (2, 3, 10), (0, 3, 3)]);
+        assert_eq!(sut.len(), 3);
+        assert_eq!(sut.iter().map(|&(_, _, w)| w).sum::<i32>(), 5);
+
+        let sut = minimum_spanning_tree(4, &[(0, 1, 2), (2, 3, 4)]);
+        assert_eq!(sut, vec![(0, 1, 2), (2, 3, 4)]);
+
+        let sut = minimum_spanning_tree::<i32>(1, &[]);
+        assert!(sut.is_empty());
+    }
+
+    #[test]
+    fn test_bipartite_matching() {
+        let edges: Vec<_> = (0..3).flat_map(|i| (0..3).map(move |j| (i, j))).collect();
+        let sut = bipartite_matching(3, 3, &edges);
+        assert!(sut.iter().all(Option::is_some));
+        assert_eq!(sut.iter().flatten().collect::<HashSet<_>>().len(), 3);
+
+        let sut = bipartite_matching(3, 3, &[(0, 0), (0, 1), (1, 0), (2, 1), (2, 2)]);
+        assert!(sut.iter().all(Option::is_some));
+        assert!(sut.iter().enumerate().all(|(i, j)| [(0, 0), (0, 1), (1, 0), (2, 1), (2, 2)].contains(&(i, j.unwrap()))));
+
+        let sut = bipartite_matching(3, 3, &[(0, 1), (1, 0), (1, 1), (2, 1)]);
+        assert_eq!(sut.iter().flatten().count(), 2);
+        assert_eq!(sut[1], Some(0));
+
+        let sut = bipartite_matching(2, 1, &[(0, 0)]);
+        assert_eq!(sut, vec![Some(0), None]);
+
+        assert!(bipartite_matching(0, 0, &[]).is_empty());
+    }
+}